@@ -0,0 +1,24 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::column_definition::ColumnDefinition;
+use crate::ast::expression::Expression;
+
+#[derive(Debug)]
+pub enum AlterTableAction {
+    AddColumn(ColumnDefinition),
+    DropColumn(Expression),
+    RenameColumn { from: Expression, to: Expression },
+    RenameTable(Expression),
+}
+
+impl fmt::Display for AlterTableAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterTableAction::AddColumn(column) => write!(f, "ADD COLUMN {}", column),
+            AlterTableAction::DropColumn(column) => write!(f, "DROP COLUMN {}", column),
+            AlterTableAction::RenameColumn { from, to } => write!(f, "RENAME COLUMN {} TO {}", from, to),
+            AlterTableAction::RenameTable(table_name) => write!(f, "RENAME TO {}", table_name),
+        }
+    }
+}
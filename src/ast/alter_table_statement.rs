@@ -0,0 +1,20 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::alter_table_action::AlterTableAction;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct AlterTableStatement {
+    pub token: Token,
+    pub span: Span,
+    pub table_name: Expression,
+    pub action: AlterTableAction,
+}
+
+impl fmt::Display for AlterTableStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} TABLE {} {}", self.token.literal, self.table_name, self.action)
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::Span;
+
+#[derive(Debug)]
+pub struct Assignment {
+    pub column: Expression,
+    pub value: Expression,
+    pub span: Span,
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.column, self.value)
+    }
+}
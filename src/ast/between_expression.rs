@@ -0,0 +1,23 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+/// `expression [NOT] BETWEEN low AND high`, with both bounds included.
+#[derive(Debug)]
+pub struct BetweenExpression {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub negated: bool,
+    pub low: Box<Expression>,
+    pub high: Box<Expression>,
+}
+
+impl fmt::Display for BetweenExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let operator = if self.negated { "NOT BETWEEN" } else { "BETWEEN" };
+        write!(f, "({} {} {} AND {})", self.expression, operator, self.low, self.high)
+    }
+}
@@ -0,0 +1,17 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct BooleanLiteral {
+    pub token: Token,
+    pub span: Span,
+    pub value: bool,
+}
+
+impl fmt::Display for BooleanLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.value { "TRUE" } else { "FALSE" })
+    }
+}
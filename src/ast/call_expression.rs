@@ -0,0 +1,54 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::ast::Node;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub enum FunctionArgument {
+    // the `*` of `count(*)`
+    Wildcard(Span),
+    Expression(Expression),
+}
+
+impl FunctionArgument {
+    pub fn span(&self) -> Span {
+        match self {
+            FunctionArgument::Wildcard(span) => *span,
+            FunctionArgument::Expression(expression) => expression.span(),
+        }
+    }
+}
+
+impl fmt::Display for FunctionArgument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FunctionArgument::Wildcard(_) => write!(f, "*"),
+            FunctionArgument::Expression(expression) => fmt::Display::fmt(&expression, f),
+        }
+    }
+}
+
+/// A function call such as `lower(name)` or an aggregate such as `count(DISTINCT id)`.
+#[derive(Debug)]
+pub struct CallExpression {
+    pub token: Token,
+    pub span: Span,
+    pub function: Box<Expression>,
+    pub distinct: bool,
+    pub arguments: Vec<FunctionArgument>,
+}
+
+impl fmt::Display for CallExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.function)?;
+
+        if self.distinct {
+            write!(f, "DISTINCT ")?;
+        }
+
+        write!(f, "{})", comma_separated(&self.arguments))
+    }
+}
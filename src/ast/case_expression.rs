@@ -0,0 +1,49 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct WhenClause {
+    pub condition: Expression,
+    pub result: Expression,
+    pub span: Span,
+}
+
+impl fmt::Display for WhenClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "WHEN {} THEN {}", self.condition, self.result)
+    }
+}
+
+/// A searched CASE tests each condition in turn. A simple CASE has an operand, and each condition
+/// is a value compared with it for equality.
+#[derive(Debug)]
+pub struct CaseExpression {
+    pub token: Token,
+    pub span: Span,
+    pub operand: Option<Box<Expression>>,
+    pub when_clauses: Vec<WhenClause>,
+    pub else_result: Option<Box<Expression>>,
+}
+
+impl fmt::Display for CaseExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.token.literal)?;
+
+        if let Some(operand) = &self.operand {
+            write!(f, "{} ", operand)?;
+        }
+
+        for when_clause in &self.when_clauses {
+            write!(f, "{} ", when_clause)?;
+        }
+
+        if let Some(else_result) = &self.else_result {
+            write!(f, "ELSE {} ", else_result)?;
+        }
+
+        write!(f, "END")
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::data_type::DataType;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+/// A conversion to another type, written `CAST(expression AS type)` or `expression::type`. Both
+/// forms display as the former.
+#[derive(Debug)]
+pub struct CastExpression {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub data_type: DataType,
+}
+
+impl fmt::Display for CastExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CAST({} AS {})", self.expression, self.data_type)
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::data_type::DataType;
+use crate::ast::expression::Expression;
+use crate::lexer::Span;
+
+#[derive(Debug)]
+pub struct ColumnDefinition {
+    pub name: Expression,
+    pub data_type: DataType,
+    pub not_null: bool,
+    pub primary_key: bool,
+    pub default: Option<Expression>,
+    pub span: Span,
+}
+
+impl fmt::Display for ColumnDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+
+        if self.not_null {
+            write!(f, " NOT NULL")?;
+        }
+
+        if self.primary_key {
+            write!(f, " PRIMARY KEY")?;
+        }
+
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {}", default)?;
+        }
+
+        Ok(())
+    }
+}
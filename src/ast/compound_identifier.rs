@@ -0,0 +1,25 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::identifier::Identifier;
+use crate::lexer::{Span, Token};
+
+/// A dotted name such as `schema.table.column`.
+#[derive(Debug)]
+pub struct CompoundIdentifier {
+    pub token: Token,
+    pub span: Span,
+    pub parts: Vec<Identifier>,
+}
+
+impl fmt::Display for CompoundIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let parts = self.parts
+            .iter()
+            .map(|part| format!("{}", part))
+            .collect::<Vec<String>>()
+            .join(".");
+
+        write!(f, "{}", parts)
+    }
+}
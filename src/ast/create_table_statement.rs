@@ -0,0 +1,28 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::column_definition::ColumnDefinition;
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct CreateTableStatement {
+    pub token: Token,
+    pub span: Span,
+    pub if_not_exists: bool,
+    pub table_name: Expression,
+    pub columns: Vec<ColumnDefinition>,
+}
+
+impl fmt::Display for CreateTableStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} TABLE ", self.token.literal)?;
+
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+
+        write!(f, "{} ({})", self.table_name, comma_separated(&self.columns))
+    }
+}
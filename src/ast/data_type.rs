@@ -0,0 +1,54 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataType {
+    Int,
+    BigInt,
+    Float,
+    Decimal { precision: Option<u32>, scale: Option<u32> },
+    Boolean,
+    Text,
+    Varchar(Option<u32>),
+    Date,
+    Timestamp,
+}
+
+impl DataType {
+    /// Maps a type name, including the usual aliases, to its data type. Parameters such as the
+    /// length of a VARCHAR are filled in by the parser afterwards.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let data_type = match name.to_uppercase().as_str() {
+            "INT" | "INTEGER" => DataType::Int,
+            "BIGINT" => DataType::BigInt,
+            "FLOAT" | "REAL" | "DOUBLE" => DataType::Float,
+            "DECIMAL" | "NUMERIC" => DataType::Decimal { precision: None, scale: None },
+            "BOOLEAN" | "BOOL" => DataType::Boolean,
+            "TEXT" => DataType::Text,
+            "VARCHAR" => DataType::Varchar(None),
+            "DATE" => DataType::Date,
+            "TIMESTAMP" => DataType::Timestamp,
+            _ => return None,
+        };
+        Some(data_type)
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Int => write!(f, "INT"),
+            DataType::BigInt => write!(f, "BIGINT"),
+            DataType::Float => write!(f, "FLOAT"),
+            DataType::Decimal { precision: None, .. } => write!(f, "DECIMAL"),
+            DataType::Decimal { precision: Some(precision), scale: None } => write!(f, "DECIMAL({})", precision),
+            DataType::Decimal { precision: Some(precision), scale: Some(scale) } => write!(f, "DECIMAL({}, {})", precision, scale),
+            DataType::Boolean => write!(f, "BOOLEAN"),
+            DataType::Text => write!(f, "TEXT"),
+            DataType::Varchar(None) => write!(f, "VARCHAR"),
+            DataType::Varchar(Some(length)) => write!(f, "VARCHAR({})", length),
+            DataType::Date => write!(f, "DATE"),
+            DataType::Timestamp => write!(f, "TIMESTAMP"),
+        }
+    }
+}
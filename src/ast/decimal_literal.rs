@@ -0,0 +1,29 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, Token};
+
+/// An exact decimal written as a typed literal such as `DECIMAL '19.99'`, kept as an unscaled integer
+/// and the number of digits after the decimal point so no precision is lost.
+#[derive(Debug)]
+pub struct DecimalLiteral {
+    pub token: Token,
+    pub span: Span,
+    pub value: i128,
+    pub scale: u32,
+}
+
+impl fmt::Display for DecimalLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let scale = self.scale as usize;
+        let sign = if self.value < 0 { "-" } else { "" };
+        let digits = format!("{:0>width$}", self.value.unsigned_abs(), width = scale + 1);
+
+        if scale == 0 {
+            write!(f, "DECIMAL '{}{}'", sign, digits)
+        } else {
+            let (whole, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "DECIMAL '{}{}.{}'", sign, whole, fraction)
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct DeleteStatement {
+    pub token: Token,
+    pub span: Span,
+    pub table_name: Expression,
+    pub where_clause: Option<Expression>,
+}
+
+impl fmt::Display for DeleteStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} FROM {}", self.token.literal, self.table_name)?;
+
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", where_clause)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct DropTableStatement {
+    pub token: Token,
+    pub span: Span,
+    pub if_exists: bool,
+    pub table_name: Expression,
+}
+
+impl fmt::Display for DropTableStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} TABLE ", self.token.literal)?;
+
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+
+        write!(f, "{}", self.table_name)
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::query::Query;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct ExistsExpression {
+    pub token: Token,
+    pub span: Span,
+    pub subquery: Box<Query>,
+}
+
+impl fmt::Display for ExistsExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.token.literal, self.subquery)
+    }
+}
@@ -1,23 +1,128 @@
 use std::fmt;
 use std::fmt::Formatter;
 
+use crate::ast::between_expression::BetweenExpression;
+use crate::ast::boolean_literal::BooleanLiteral;
+use crate::ast::call_expression::CallExpression;
+use crate::ast::case_expression::CaseExpression;
+use crate::ast::cast_expression::CastExpression;
+use crate::ast::compound_identifier::CompoundIdentifier;
+use crate::ast::decimal_literal::DecimalLiteral;
+use crate::ast::exists_expression::ExistsExpression;
+use crate::ast::float_literal::FloatLiteral;
 use crate::ast::identifier::Identifier;
+use crate::ast::in_list::InList;
+use crate::ast::in_subquery::InSubquery;
+use crate::ast::infix_expression::InfixExpression;
 use crate::ast::int_literal::IntLiteral;
+use crate::ast::is_null_expression::IsNullExpression;
+use crate::ast::like_expression::LikeExpression;
+use crate::ast::null_literal::NullLiteral;
 use crate::ast::prefix_expression::PrefixExpression;
+use crate::ast::string_literal::StringLiteral;
+use crate::ast::subquery::Subquery;
+use crate::ast::Node;
+use crate::lexer::Span;
 
 #[derive(Debug)]
 pub enum Expression {
     Identifier(Identifier),
+    CompoundIdentifier(CompoundIdentifier),
     Int(IntLiteral),
+    Float(FloatLiteral),
+    Decimal(DecimalLiteral),
+    String(StringLiteral),
     Prefixed(PrefixExpression),
+    Infixed(InfixExpression),
+    Call(CallExpression),
+    Subquery(Subquery),
+    Exists(ExistsExpression),
+    InSubquery(InSubquery),
+    Boolean(BooleanLiteral),
+    Null(NullLiteral),
+    Case(CaseExpression),
+    InList(InList),
+    Between(BetweenExpression),
+    Like(LikeExpression),
+    IsNull(IsNullExpression),
+    Cast(CastExpression),
+}
+
+impl Node for Expression {
+    fn token_literal(&self) -> String {
+        match self {
+            Expression::Identifier(node) => node.token.literal.clone(),
+            Expression::CompoundIdentifier(node) => node.token.literal.clone(),
+            Expression::Int(node) => node.token.literal.clone(),
+            Expression::Float(node) => node.token.literal.clone(),
+            Expression::Decimal(node) => node.token.literal.clone(),
+            Expression::String(node) => node.token.literal.clone(),
+            Expression::Prefixed(node) => node.token.literal.clone(),
+            Expression::Infixed(node) => node.token.literal.clone(),
+            Expression::Call(node) => node.token.literal.clone(),
+            Expression::Subquery(node) => node.token.literal.clone(),
+            Expression::Exists(node) => node.token.literal.clone(),
+            Expression::InSubquery(node) => node.token.literal.clone(),
+            Expression::Boolean(node) => node.token.literal.clone(),
+            Expression::Null(node) => node.token.literal.clone(),
+            Expression::Case(node) => node.token.literal.clone(),
+            Expression::InList(node) => node.token.literal.clone(),
+            Expression::Between(node) => node.token.literal.clone(),
+            Expression::Like(node) => node.token.literal.clone(),
+            Expression::IsNull(node) => node.token.literal.clone(),
+            Expression::Cast(node) => node.token.literal.clone(),
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Expression::Identifier(node) => node.span,
+            Expression::CompoundIdentifier(node) => node.span,
+            Expression::Int(node) => node.span,
+            Expression::Float(node) => node.span,
+            Expression::Decimal(node) => node.span,
+            Expression::String(node) => node.span,
+            Expression::Prefixed(node) => node.span,
+            Expression::Infixed(node) => node.span,
+            Expression::Call(node) => node.span,
+            Expression::Subquery(node) => node.span,
+            Expression::Exists(node) => node.span,
+            Expression::InSubquery(node) => node.span,
+            Expression::Boolean(node) => node.span,
+            Expression::Null(node) => node.span,
+            Expression::Case(node) => node.span,
+            Expression::InList(node) => node.span,
+            Expression::Between(node) => node.span,
+            Expression::Like(node) => node.span,
+            Expression::IsNull(node) => node.span,
+            Expression::Cast(node) => node.span,
+        }
+    }
 }
 
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Expression::Identifier(expression) => fmt::Display::fmt(&expression, f),
+            Expression::CompoundIdentifier(compound_identifier) => fmt::Display::fmt(&compound_identifier, f),
             Expression::Int(int_literal) => fmt::Display::fmt(&int_literal, f),
+            Expression::Float(float_literal) => fmt::Display::fmt(&float_literal, f),
+            Expression::Decimal(decimal_literal) => fmt::Display::fmt(&decimal_literal, f),
+            Expression::String(string_literal) => fmt::Display::fmt(&string_literal, f),
             Expression::Prefixed(prefix_expression) => fmt::Display::fmt(&prefix_expression, f),
+            Expression::Infixed(infix_expression) => fmt::Display::fmt(&infix_expression, f),
+            Expression::Call(call_expression) => fmt::Display::fmt(&call_expression, f),
+            Expression::Subquery(subquery) => fmt::Display::fmt(&subquery, f),
+            Expression::Exists(exists_expression) => fmt::Display::fmt(&exists_expression, f),
+            Expression::InSubquery(in_subquery) => fmt::Display::fmt(&in_subquery, f),
+            Expression::Boolean(boolean_literal) => fmt::Display::fmt(&boolean_literal, f),
+            Expression::Null(null_literal) => fmt::Display::fmt(&null_literal, f),
+            Expression::Case(case_expression) => fmt::Display::fmt(&case_expression, f),
+            Expression::InList(in_list) => fmt::Display::fmt(&in_list, f),
+            Expression::Between(between_expression) => fmt::Display::fmt(&between_expression, f),
+            Expression::Like(like_expression) => fmt::Display::fmt(&like_expression, f),
+            Expression::IsNull(is_null_expression) => fmt::Display::fmt(&is_null_expression, f),
+            Expression::Cast(cast_expression) => fmt::Display::fmt(&cast_expression, f),
         }
     }
 }
@@ -0,0 +1,18 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct FloatLiteral {
+    pub token: Token,
+    pub span: Span,
+    pub value: f64,
+}
+
+impl fmt::Display for FloatLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // the debug form always keeps a fraction or exponent, so the output lexes as a float again
+        write!(f, "{:?}", self.value)
+    }
+}
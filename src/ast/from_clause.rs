@@ -0,0 +1,20 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::table_reference::TableReference;
+use crate::lexer::{Span, Token};
+
+/// The FROM clause of a query. Comma separated references are cross joined.
+#[derive(Debug)]
+pub struct FromClause {
+    pub token: Token,
+    pub span: Span,
+    pub tables: Vec<TableReference>,
+}
+
+impl fmt::Display for FromClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.token.literal, comma_separated(&self.tables))
+    }
+}
@@ -1,16 +1,23 @@
 use std::fmt;
 use std::fmt::Formatter;
 
-use crate::lexer::Token;
+use crate::lexer::{Span, Token};
 
 #[derive(Debug)]
 pub struct Identifier {
     pub token: Token,
+    pub span: Span,
     pub value: String,
+    // quoted identifiers keep their case and may be reserved words
+    pub quoted: bool,
 }
 
 impl fmt::Display for Identifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.value)
+        if self.quoted {
+            write!(f, "\"{}\"", self.value.replace('"', "\"\""))
+        } else {
+            write!(f, "{}", self.value)
+        }
     }
 }
@@ -0,0 +1,23 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+/// `expression [NOT] IN (value, ...)`
+#[derive(Debug)]
+pub struct InList {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub negated: bool,
+    pub list: Vec<Expression>,
+}
+
+impl fmt::Display for InList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let operator = if self.negated { "NOT IN" } else { "IN" };
+        write!(f, "({} {} ({}))", self.expression, operator, comma_separated(&self.list))
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::ast::query::Query;
+use crate::lexer::{Span, Token};
+
+/// `expression [NOT] IN (SELECT ...)`
+#[derive(Debug)]
+pub struct InSubquery {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub negated: bool,
+    pub subquery: Box<Query>,
+}
+
+impl fmt::Display for InSubquery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let operator = if self.negated { "NOT IN" } else { "IN" };
+        write!(f, "({} {} ({}))", self.expression, operator, self.subquery)
+    }
+}
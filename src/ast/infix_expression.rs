@@ -0,0 +1,21 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::ast::operator::BinaryOperator;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct InfixExpression {
+    pub token: Token,
+    pub span: Span,
+    pub left: Box<Expression>,
+    pub operator: BinaryOperator,
+    pub right: Box<Expression>,
+}
+
+impl fmt::Display for InfixExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({} {} {})", self.left, self.operator, self.right)
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct InsertStatement {
+    pub token: Token,
+    pub span: Span,
+    pub table_name: Expression,
+    pub columns: Vec<Expression>,
+    pub rows: Vec<Vec<Expression>>,
+}
+
+impl fmt::Display for InsertStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} INTO {}", self.token.literal, self.table_name)?;
+
+        if !self.columns.is_empty() {
+            write!(f, " ({})", comma_separated(&self.columns))?;
+        }
+
+        let rows = self.rows
+            .iter()
+            .map(|row| format!("({})", comma_separated(row)))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, " VALUES {}", rows)
+    }
+}
@@ -1,11 +1,12 @@
 use std::fmt;
 use std::fmt::Formatter;
 
-use crate::lexer::Token;
+use crate::lexer::{Span, Token};
 
 #[derive(Debug)]
 pub struct IntLiteral {
     pub token: Token,
+    pub span: Span,
     pub value: i64,
 }
 
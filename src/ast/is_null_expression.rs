@@ -0,0 +1,21 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+/// `expression IS [NOT] NULL`
+#[derive(Debug)]
+pub struct IsNullExpression {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub negated: bool,
+}
+
+impl fmt::Display for IsNullExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let operator = if self.negated { "IS NOT NULL" } else { "IS NULL" };
+        write!(f, "({} {})", self.expression, operator)
+    }
+}
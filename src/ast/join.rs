@@ -0,0 +1,63 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::ast::table_reference::TableReference;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    FullOuter,
+    Cross,
+}
+
+impl fmt::Display for JoinKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinKind::Inner => write!(f, "INNER JOIN"),
+            JoinKind::Left => write!(f, "LEFT JOIN"),
+            JoinKind::Right => write!(f, "RIGHT JOIN"),
+            JoinKind::FullOuter => write!(f, "FULL OUTER JOIN"),
+            JoinKind::Cross => write!(f, "CROSS JOIN"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum JoinConstraint {
+    On(Expression),
+    Using(Vec<Expression>),
+    None,
+}
+
+#[derive(Debug)]
+pub struct Join {
+    pub token: Token,
+    pub span: Span,
+    pub kind: JoinKind,
+    pub left: Box<TableReference>,
+    pub right: Box<TableReference>,
+    pub constraint: JoinConstraint,
+}
+
+impl fmt::Display for Join {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} ", self.left, self.kind)?;
+
+        // a join on the right only came from parentheses, which keep its constraint attached to it
+        match self.right.as_ref() {
+            TableReference::Join(join) => write!(f, "({})", join)?,
+            right => write!(f, "{}", right)?,
+        }
+
+        match &self.constraint {
+            JoinConstraint::On(expression) => write!(f, " ON {}", expression),
+            JoinConstraint::Using(columns) => write!(f, " USING ({})", comma_separated(columns)),
+            JoinConstraint::None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+/// `expression [NOT] LIKE pattern [ESCAPE escape]`
+#[derive(Debug)]
+pub struct LikeExpression {
+    pub token: Token,
+    pub span: Span,
+    pub expression: Box<Expression>,
+    pub negated: bool,
+    pub pattern: Box<Expression>,
+    pub escape: Option<Box<Expression>>,
+}
+
+impl fmt::Display for LikeExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let operator = if self.negated { "NOT LIKE" } else { "LIKE" };
+        write!(f, "({} {} {}", self.expression, operator, self.pattern)?;
+
+        if let Some(escape) = &self.escape {
+            write!(f, " ESCAPE {}", escape)?;
+        }
+
+        write!(f, ")")
+    }
+}
@@ -1,18 +1,61 @@
-use std::fmt;
-use std::fmt::Formatter;
+use std::fmt::Display;
 
-use expression::Expression;
-
-use crate::lexer::Token;
+use crate::lexer::Span;
 
+pub mod program;
 pub mod statement;
+pub mod query;
+pub mod with_clause;
+pub mod set_expression;
 pub mod select_statement;
+pub mod select_item;
+pub mod order_by_expression;
+pub mod from_clause;
+pub mod table_reference;
+pub mod join;
+pub mod insert_statement;
+pub mod update_statement;
+pub mod delete_statement;
+pub mod create_table_statement;
+pub mod drop_table_statement;
+pub mod alter_table_statement;
+pub mod alter_table_action;
+pub mod column_definition;
+pub mod data_type;
+pub mod assignment;
 pub mod expression;
 pub mod identifier;
+pub mod compound_identifier;
 pub mod int_literal;
+pub mod float_literal;
+pub mod decimal_literal;
+pub mod string_literal;
+pub mod boolean_literal;
+pub mod null_literal;
+pub mod operator;
 pub mod prefix_expression;
+pub mod infix_expression;
+pub mod call_expression;
+pub mod subquery;
+pub mod exists_expression;
+pub mod in_subquery;
+pub mod in_list;
+pub mod between_expression;
+pub mod like_expression;
+pub mod is_null_expression;
+pub mod case_expression;
+pub mod cast_expression;
 
 pub trait Node {
     fn token_literal(&self) -> String;
+    fn span(&self) -> Span;
 }
 
+/// Renders a list of nodes separated by commas, the way SQL prints column and value lists.
+pub(crate) fn comma_separated<T: Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| format!("{}", item))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct NullLiteral {
+    pub token: Token,
+    pub span: Span,
+}
+
+impl fmt::Display for NullLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "NULL")
+    }
+}
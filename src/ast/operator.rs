@@ -0,0 +1,130 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::TokenKind;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum UnaryOperator {
+    Not,
+    Minus,
+}
+
+impl UnaryOperator {
+    /// `!` is accepted as another spelling of NOT.
+    pub fn from_token_kind(kind: TokenKind) -> Option<Self> {
+        match kind {
+            TokenKind::Not | TokenKind::Bang => Some(UnaryOperator::Not),
+            TokenKind::Minus => Some(UnaryOperator::Minus),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            UnaryOperator::Not => write!(f, "NOT"),
+            UnaryOperator::Minus => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BinaryOperator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    LtEq,
+    GtEq,
+    And,
+    Or,
+}
+
+impl BinaryOperator {
+    pub fn from_token_kind(kind: TokenKind) -> Option<Self> {
+        match kind {
+            TokenKind::Plus => Some(BinaryOperator::Plus),
+            TokenKind::Minus => Some(BinaryOperator::Minus),
+            TokenKind::Asterisk => Some(BinaryOperator::Multiply),
+            TokenKind::Slash => Some(BinaryOperator::Divide),
+            TokenKind::Equals => Some(BinaryOperator::Eq),
+            TokenKind::NotEq => Some(BinaryOperator::NotEq),
+            TokenKind::Lt => Some(BinaryOperator::Lt),
+            TokenKind::Gt => Some(BinaryOperator::Gt),
+            TokenKind::LtEq => Some(BinaryOperator::LtEq),
+            TokenKind::GtEq => Some(BinaryOperator::GtEq),
+            TokenKind::And => Some(BinaryOperator::And),
+            TokenKind::Or => Some(BinaryOperator::Or),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            BinaryOperator::Plus => "+",
+            BinaryOperator::Minus => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Eq => "=",
+            BinaryOperator::NotEq => "!=",
+            BinaryOperator::Lt => "<",
+            BinaryOperator::Gt => ">",
+            BinaryOperator::LtEq => "<=",
+            BinaryOperator::GtEq => ">=",
+            BinaryOperator::And => "AND",
+            BinaryOperator::Or => "OR",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unary_operators() {
+        for (kind, operator, symbol) in [
+            (TokenKind::Not, UnaryOperator::Not, "NOT"),
+            (TokenKind::Bang, UnaryOperator::Not, "NOT"),
+            (TokenKind::Minus, UnaryOperator::Minus, "-"),
+        ] {
+            assert_eq!(UnaryOperator::from_token_kind(kind), Some(operator));
+            assert_eq!(operator.to_string(), symbol);
+        }
+
+        assert_eq!(UnaryOperator::from_token_kind(TokenKind::Plus), None);
+    }
+
+    #[test]
+    fn binary_operators() {
+        for (kind, operator, symbol) in [
+            (TokenKind::Plus, BinaryOperator::Plus, "+"),
+            (TokenKind::Minus, BinaryOperator::Minus, "-"),
+            (TokenKind::Asterisk, BinaryOperator::Multiply, "*"),
+            (TokenKind::Slash, BinaryOperator::Divide, "/"),
+            (TokenKind::Equals, BinaryOperator::Eq, "="),
+            (TokenKind::NotEq, BinaryOperator::NotEq, "!="),
+            (TokenKind::Lt, BinaryOperator::Lt, "<"),
+            (TokenKind::Gt, BinaryOperator::Gt, ">"),
+            (TokenKind::LtEq, BinaryOperator::LtEq, "<="),
+            (TokenKind::GtEq, BinaryOperator::GtEq, ">="),
+            (TokenKind::And, BinaryOperator::And, "AND"),
+            (TokenKind::Or, BinaryOperator::Or, "OR"),
+        ] {
+            assert_eq!(BinaryOperator::from_token_kind(kind), Some(operator));
+            assert_eq!(operator.to_string(), symbol);
+        }
+
+        for kind in [TokenKind::Not, TokenKind::Bang, TokenKind::In, TokenKind::Identifier] {
+            assert_eq!(BinaryOperator::from_token_kind(kind), None);
+        }
+    }
+}
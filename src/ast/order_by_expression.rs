@@ -0,0 +1,33 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::lexer::Span;
+
+/// One sort key of an ORDER BY clause. The direction and null ordering are
+/// `None` when the query left them to the default.
+#[derive(Debug)]
+pub struct OrderByExpression {
+    pub expression: Expression,
+    pub asc: Option<bool>,
+    pub nulls_first: Option<bool>,
+    pub span: Span,
+}
+
+impl fmt::Display for OrderByExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.expression, f)?;
+
+        match self.asc {
+            Some(true) => write!(f, " ASC")?,
+            Some(false) => write!(f, " DESC")?,
+            None => {}
+        }
+
+        match self.nulls_first {
+            Some(true) => write!(f, " NULLS FIRST"),
+            Some(false) => write!(f, " NULLS LAST"),
+            None => Ok(()),
+        }
+    }
+}
@@ -2,18 +2,27 @@ use std::fmt;
 use std::fmt::Formatter;
 
 use crate::ast::expression::Expression;
-use crate::lexer::Token;
+use crate::ast::operator::UnaryOperator;
+use crate::lexer::{Span, Token};
 
 #[derive(Debug)]
 pub struct PrefixExpression {
     pub token: Token,
-    // TODO use enum
-    pub operator: String,
+    pub span: Span,
+    pub operator: UnaryOperator,
     pub right: Box<Expression>,
 }
 
 impl fmt::Display for PrefixExpression {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "({}{})", self.operator, self.right)
+        let right = self.right.to_string();
+
+        // keyword operators such as NOT need a space before their operand, and so does a minus in
+        // front of a negative number, since `--` would start a comment
+        match self.operator {
+            UnaryOperator::Not => write!(f, "({} {})", self.operator, right),
+            UnaryOperator::Minus if right.starts_with('-') => write!(f, "({} {})", self.operator, right),
+            UnaryOperator::Minus => write!(f, "({}{})", self.operator, right),
+        }
     }
 }
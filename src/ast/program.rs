@@ -0,0 +1,40 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::Node;
+use crate::ast::statement::Statement;
+use crate::lexer::Span;
+
+/// A whole script: the statements of a .sql file in order.
+#[derive(Debug)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+impl Node for Program {
+    fn token_literal(&self) -> String {
+        self.statements
+            .first()
+            .map(|statement| statement.token_literal())
+            .unwrap_or_default()
+    }
+
+    fn span(&self) -> Span {
+        match (self.statements.first(), self.statements.last()) {
+            (Some(first), Some(last)) => first.span().to(last.span()),
+            _ => Span::default(),
+        }
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let statements = self.statements
+            .iter()
+            .map(|statement| format!("{};", statement))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write!(f, "{}", statements)
+    }
+}
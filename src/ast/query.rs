@@ -0,0 +1,47 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::ast::order_by_expression::OrderByExpression;
+use crate::ast::set_expression::SetExpression;
+use crate::ast::with_clause::WithClause;
+use crate::lexer::{Span, Token};
+
+/// A complete query: a body, optionally preceded by the common table expressions it reads from.
+/// ORDER BY, LIMIT and OFFSET belong here rather than to a SELECT since they apply to the rows of
+/// the whole body, set operations included.
+#[derive(Debug)]
+pub struct Query {
+    pub token: Token,
+    pub span: Span,
+    pub with: Option<WithClause>,
+    pub body: SetExpression,
+    pub order_by: Vec<OrderByExpression>,
+    pub limit: Option<Expression>,
+    pub offset: Option<Expression>,
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(with) = &self.with {
+            write!(f, "{} ", with)?;
+        }
+
+        fmt::Display::fmt(&self.body, f)?;
+
+        if !self.order_by.is_empty() {
+            write!(f, " ORDER BY {}", comma_separated(&self.order_by))?;
+        }
+
+        if let Some(limit) = &self.limit {
+            write!(f, " LIMIT {}", limit)?;
+        }
+
+        if let Some(offset) = &self.offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::ast::Node;
+use crate::lexer::Span;
+
+/// One entry of a select list: `*`, `table.*` or any expression with an optional alias.
+#[derive(Debug)]
+pub enum SelectItem {
+    Wildcard(Span),
+    QualifiedWildcard { table: Expression, span: Span },
+    Expression { expression: Expression, alias: Option<Expression> },
+}
+
+impl SelectItem {
+    pub fn span(&self) -> Span {
+        match self {
+            SelectItem::Wildcard(span) => *span,
+            SelectItem::QualifiedWildcard { span, .. } => *span,
+            SelectItem::Expression { expression, alias: None } => expression.span(),
+            SelectItem::Expression { expression, alias: Some(alias) } => expression.span().to(alias.span()),
+        }
+    }
+}
+
+impl fmt::Display for SelectItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectItem::Wildcard(_) => write!(f, "*"),
+            SelectItem::QualifiedWildcard { table, .. } => write!(f, "{}.*", table),
+            SelectItem::Expression { expression, alias: None } => fmt::Display::fmt(&expression, f),
+            SelectItem::Expression { expression, alias: Some(alias) } => write!(f, "{} AS {}", expression, alias),
+        }
+    }
+}
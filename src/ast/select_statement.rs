@@ -1,33 +1,41 @@
 use std::fmt;
 use std::fmt::Formatter;
 
+use crate::ast::comma_separated;
 use crate::ast::expression::Expression;
-use crate::ast::Node;
-use crate::lexer::Token;
+use crate::ast::from_clause::FromClause;
+use crate::ast::select_item::SelectItem;
+use crate::lexer::{Span, Token};
 
 #[derive(Debug)]
 pub struct SelectStatement {
     pub token: Token,
-    pub table_name: Expression,
-    pub expressions: Vec<Expression>,
-}
-
-impl SelectStatement {
-    pub fn new(token: Token, table_name: Expression, expressions: Vec<Expression>) -> Self {
-        SelectStatement { token, table_name, expressions }
-    }
+    pub span: Span,
+    pub from: FromClause,
+    pub projection: Vec<SelectItem>,
+    pub where_clause: Option<Expression>,
+    pub group_by: Vec<Expression>,
+    pub having: Option<Expression>,
 }
 
 impl fmt::Display for SelectStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{} ", self.token.literal)?;
 
-        let expressions = self.expressions
-            .iter()
-            .map(|expression| format!("{}", expression))
-            .collect::<Vec<String>>()
-            .join(", ");
+        write!(f, "{} {}", comma_separated(&self.projection), self.from)?;
+
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", where_clause)?;
+        }
+
+        if !self.group_by.is_empty() {
+            write!(f, " GROUP BY {}", comma_separated(&self.group_by))?;
+        }
+
+        if let Some(having) = &self.having {
+            write!(f, " HAVING {}", having)?;
+        }
 
-        write!(f, "{} FROM {}", expressions, self.table_name)
+        Ok(())
     }
 }
@@ -0,0 +1,74 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::query::Query;
+use crate::ast::select_statement::SelectStatement;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum SetOperator {
+    Union,
+    Intersect,
+    Except,
+}
+
+impl fmt::Display for SetOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SetOperator::Union => write!(f, "UNION"),
+            SetOperator::Intersect => write!(f, "INTERSECT"),
+            SetOperator::Except => write!(f, "EXCEPT"),
+        }
+    }
+}
+
+/// Two query bodies combined by a set operator. Without ALL duplicate rows are removed.
+#[derive(Debug)]
+pub struct SetOperation {
+    pub token: Token,
+    pub span: Span,
+    pub operator: SetOperator,
+    pub all: bool,
+    pub left: Box<SetExpression>,
+    pub right: Box<SetExpression>,
+}
+
+impl fmt::Display for SetOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.left, self.operator)?;
+
+        if self.all {
+            write!(f, " ALL")?;
+        }
+
+        write!(f, " {}", self.right)
+    }
+}
+
+/// The body of a query: a single SELECT, a parenthesized query, or set operations over them.
+#[derive(Debug)]
+pub enum SetExpression {
+    Select(Box<SelectStatement>),
+    Query(Box<Query>),
+    Operation(SetOperation),
+}
+
+impl SetExpression {
+    pub fn span(&self) -> Span {
+        match self {
+            SetExpression::Select(select) => select.span,
+            SetExpression::Query(query) => query.span,
+            SetExpression::Operation(operation) => operation.span,
+        }
+    }
+}
+
+impl fmt::Display for SetExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SetExpression::Select(select) => fmt::Display::fmt(&select, f),
+            SetExpression::Query(query) => write!(f, "({})", query),
+            SetExpression::Operation(operation) => fmt::Display::fmt(&operation, f),
+        }
+    }
+}
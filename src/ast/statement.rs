@@ -1,30 +1,67 @@
 use std::fmt;
-use std::fmt::{Debug, Formatter};
+use std::fmt::Formatter;
 
+use crate::ast::alter_table_statement::AlterTableStatement;
+use crate::ast::create_table_statement::CreateTableStatement;
+use crate::ast::delete_statement::DeleteStatement;
+use crate::ast::drop_table_statement::DropTableStatement;
 use crate::ast::expression::Expression;
+use crate::ast::insert_statement::InsertStatement;
 use crate::ast::Node;
-use crate::ast::select_statement::SelectStatement;
-use crate::lexer::Token;
+use crate::ast::update_statement::UpdateStatement;
+use crate::ast::query::Query;
+use crate::lexer::{Span, Token};
 
 #[derive(Debug)]
 pub enum Statement {
-    Select(SelectStatement),
+    Query(Box<Query>),
+    Insert(InsertStatement),
+    Update(UpdateStatement),
+    Delete(DeleteStatement),
+    CreateTable(CreateTableStatement),
+    DropTable(DropTableStatement),
+    AlterTable(AlterTableStatement),
     Expr(ExpressionStatement),
 }
 
 impl Node for Statement {
     fn token_literal(&self) -> String {
         match self {
-            Statement::Select(select_statement) => select_statement.token.literal.clone(),
+            Statement::Query(query) => query.token.literal.clone(),
+            Statement::Insert(insert_statement) => insert_statement.token.literal.clone(),
+            Statement::Update(update_statement) => update_statement.token.literal.clone(),
+            Statement::Delete(delete_statement) => delete_statement.token.literal.clone(),
+            Statement::CreateTable(create_table_statement) => create_table_statement.token.literal.clone(),
+            Statement::DropTable(drop_table_statement) => drop_table_statement.token.literal.clone(),
+            Statement::AlterTable(alter_table_statement) => alter_table_statement.token.literal.clone(),
             Statement::Expr(expression) => expression.token.literal.clone(),
         }
     }
+
+    fn span(&self) -> Span {
+        match self {
+            Statement::Query(query) => query.span,
+            Statement::Insert(insert_statement) => insert_statement.span,
+            Statement::Update(update_statement) => update_statement.span,
+            Statement::Delete(delete_statement) => delete_statement.span,
+            Statement::CreateTable(create_table_statement) => create_table_statement.span,
+            Statement::DropTable(drop_table_statement) => drop_table_statement.span,
+            Statement::AlterTable(alter_table_statement) => alter_table_statement.span,
+            Statement::Expr(expression) => expression.span,
+        }
+    }
 }
 
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Statement::Select(select) => fmt::Display::fmt(&select, f),
+            Statement::Query(query) => fmt::Display::fmt(&query, f),
+            Statement::Insert(insert) => fmt::Display::fmt(&insert, f),
+            Statement::Update(update) => fmt::Display::fmt(&update, f),
+            Statement::Delete(delete) => fmt::Display::fmt(&delete, f),
+            Statement::CreateTable(create_table) => fmt::Display::fmt(&create_table, f),
+            Statement::DropTable(drop_table) => fmt::Display::fmt(&drop_table, f),
+            Statement::AlterTable(alter_table) => fmt::Display::fmt(&alter_table, f),
             Statement::Expr(expression) => fmt::Display::fmt(&expression, f),
         }
     }
@@ -33,6 +70,7 @@ impl fmt::Display for Statement {
 #[derive(Debug)]
 pub struct ExpressionStatement {
     pub token: Token,
+    pub span: Span,
     pub expression: Expression,
 }
 
@@ -0,0 +1,17 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct StringLiteral {
+    pub token: Token,
+    pub span: Span,
+    pub value: String,
+}
+
+impl fmt::Display for StringLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.value.replace('\'', "''"))
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::query::Query;
+use crate::lexer::{Span, Token};
+
+/// A parenthesized query used as a scalar value.
+#[derive(Debug)]
+pub struct Subquery {
+    pub token: Token,
+    pub span: Span,
+    pub query: Box<Query>,
+}
+
+impl fmt::Display for Subquery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", self.query)
+    }
+}
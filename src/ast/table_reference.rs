@@ -0,0 +1,37 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::expression::Expression;
+use crate::ast::join::Join;
+use crate::ast::query::Query;
+use crate::lexer::Span;
+
+/// Something rows can be read from in a FROM clause: a named table, a parenthesized query or a join
+/// of two references.
+#[derive(Debug)]
+pub enum TableReference {
+    Table { name: Expression, alias: Option<Expression>, span: Span },
+    Derived { subquery: Box<Query>, alias: Option<Expression>, span: Span },
+    Join(Join),
+}
+
+impl TableReference {
+    pub fn span(&self) -> Span {
+        match self {
+            TableReference::Table { span, .. } | TableReference::Derived { span, .. } => *span,
+            TableReference::Join(join) => join.span,
+        }
+    }
+}
+
+impl fmt::Display for TableReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TableReference::Table { name, alias: None, .. } => write!(f, "{}", name),
+            TableReference::Table { name, alias: Some(alias), .. } => write!(f, "{} AS {}", name, alias),
+            TableReference::Derived { subquery, alias: None, .. } => write!(f, "({})", subquery),
+            TableReference::Derived { subquery, alias: Some(alias), .. } => write!(f, "({}) AS {}", subquery, alias),
+            TableReference::Join(join) => fmt::Display::fmt(&join, f),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::assignment::Assignment;
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::lexer::{Span, Token};
+
+#[derive(Debug)]
+pub struct UpdateStatement {
+    pub token: Token,
+    pub span: Span,
+    pub table_name: Expression,
+    pub assignments: Vec<Assignment>,
+    pub where_clause: Option<Expression>,
+}
+
+impl fmt::Display for UpdateStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} SET {}", self.token.literal, self.table_name, comma_separated(&self.assignments))?;
+
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", where_clause)?;
+        }
+
+        Ok(())
+    }
+}
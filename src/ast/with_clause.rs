@@ -0,0 +1,49 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::ast::comma_separated;
+use crate::ast::expression::Expression;
+use crate::ast::query::Query;
+use crate::lexer::{Span, Token};
+
+/// A named query defined by a WITH clause, `name [(columns)] AS (query)`.
+#[derive(Debug)]
+pub struct CommonTableExpression {
+    pub name: Expression,
+    pub columns: Vec<Expression>,
+    pub query: Box<Query>,
+    pub span: Span,
+}
+
+impl fmt::Display for CommonTableExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        if !self.columns.is_empty() {
+            write!(f, " ({})", comma_separated(&self.columns))?;
+        }
+
+        write!(f, " AS ({})", self.query)
+    }
+}
+
+/// With RECURSIVE set, each expression may refer to itself as well as to the ones before it.
+#[derive(Debug)]
+pub struct WithClause {
+    pub token: Token,
+    pub span: Span,
+    pub recursive: bool,
+    pub ctes: Vec<CommonTableExpression>,
+}
+
+impl fmt::Display for WithClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.token.literal)?;
+
+        if self.recursive {
+            write!(f, "RECURSIVE ")?;
+        }
+
+        write!(f, "{}", comma_separated(&self.ctes))
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::lexer::{Span, TokenKind};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseError {
+    UnexpectedToken { expected: Vec<TokenKind>, found: TokenKind, span: Span },
+    // for words such as KEY that are only keywords in one place and are lexed as identifiers
+    ExpectedKeyword { expected: Vec<&'static str>, found: TokenKind, span: Span },
+    ExpectedExpression { found: TokenKind, span: Span },
+    UnknownStatement { found: TokenKind, span: Span },
+    UnterminatedLiteral { span: Span },
+    EmptyIdentifier { span: Span },
+    NumberOutOfRange { kind: TokenKind, literal: String, span: Span },
+    InvalidDecimal { literal: String, span: Span },
+    RowWidthMismatch { expected: usize, found: usize, span: Span },
+    UnknownDataType { name: String, span: Span },
+    InvalidTypeParameter { literal: String, span: Span },
+    NotCallable { span: Span },
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::ExpectedKeyword { span, .. }
+            | ParseError::ExpectedExpression { span, .. }
+            | ParseError::UnknownStatement { span, .. }
+            | ParseError::UnterminatedLiteral { span }
+            | ParseError::EmptyIdentifier { span }
+            | ParseError::NumberOutOfRange { span, .. }
+            | ParseError::InvalidDecimal { span, .. }
+            | ParseError::RowWidthMismatch { span, .. }
+            | ParseError::UnknownDataType { span, .. }
+            | ParseError::InvalidTypeParameter { span, .. }
+            | ParseError::NotCallable { span } => *span,
+        }
+    }
+
+    /// Renders the error together with the offending source line, marking the span with carets:
+    ///
+    /// ```text
+    /// error: expected From, found Identifier
+    ///  --> line 1, column 13
+    ///   |
+    /// 1 | select name employee
+    ///   |             ^^^^^^^^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let span = self.span();
+        let line = source.lines().nth(span.line - 1).unwrap_or("");
+
+        // the span may run past the end of its line, only mark the part on this line
+        let on_line = line.chars().count().saturating_sub(span.column - 1);
+        let marked = source.get(span.start..span.end).unwrap_or("").chars().count();
+        let carets = marked.min(on_line).max(1);
+
+        let number = span.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        format!(
+            "error: {}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter, span.line, span.column,
+            gutter,
+            number, line,
+            gutter, " ".repeat(span.column - 1), "^".repeat(carets),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { expected, found, .. } => {
+                let expected = expected.iter().map(|kind| format!("{:?}", kind)).collect::<Vec<String>>();
+                write!(f, "expected {}, found {:?}", alternatives(&expected), found)
+            }
+            ParseError::ExpectedKeyword { expected, found, .. } => {
+                let expected = expected.iter().map(|keyword| keyword.to_string()).collect::<Vec<String>>();
+                write!(f, "expected {}, found {:?}", alternatives(&expected), found)
+            }
+            ParseError::ExpectedExpression { found, .. } => write!(f, "expected an expression, found {:?}", found),
+            ParseError::UnknownStatement { found, .. } => write!(f, "unknown statement starting with {:?}", found),
+            ParseError::UnterminatedLiteral { .. } => write!(f, "unterminated string literal"),
+            ParseError::EmptyIdentifier { .. } => write!(f, "quoted identifier is empty"),
+            ParseError::NumberOutOfRange { kind, literal, .. } => {
+                let kind = match kind {
+                    TokenKind::Float => "float",
+                    _ => "integer",
+                };
+                write!(f, "{} literal {} is out of range", kind, literal)
+            }
+            ParseError::InvalidDecimal { literal, .. } => write!(f, "invalid decimal literal '{}'", literal),
+            ParseError::RowWidthMismatch { expected, found, .. } => write!(f, "expected {} values in row, found {}", expected, found),
+            ParseError::UnknownDataType { name, .. } => write!(f, "unknown data type {}", name),
+            ParseError::InvalidTypeParameter { literal, .. } => write!(f, "invalid type parameter {}", literal),
+            ParseError::NotCallable { .. } => write!(f, "only named functions can be called"),
+        }
+    }
+}
+
+/// Joins choices the way the messages read them, `A, B or C`.
+fn alternatives(choices: &[String]) -> String {
+    match choices.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => choices.join(""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let error = ParseError::UnexpectedToken {
+            expected: vec![TokenKind::From],
+            found: TokenKind::Identifier,
+            span: Span { start: 12, end: 20, line: 1, column: 13 },
+        };
+        assert_eq!(error.render("select name employee"), "\
+error: expected From, found Identifier
+ --> line 1, column 13
+  |
+1 | select name employee
+  |             ^^^^^^^^");
+    }
+
+    #[test]
+    fn render_later_line_and_eof() {
+        let source = "select name\nfrom";
+        let error = ParseError::UnexpectedToken {
+            expected: vec![TokenKind::Identifier],
+            found: TokenKind::Eof,
+            span: Span { start: 16, end: 16, line: 2, column: 5 },
+        };
+        assert_eq!(error.render(source), "\
+error: expected Identifier, found Eof
+ --> line 2, column 5
+  |
+2 | from
+  |     ^");
+    }
+
+    #[test]
+    fn render_span_across_lines() {
+        let source = "insert into t values (1,\n2), (3)";
+        let error = ParseError::RowWidthMismatch { expected: 2, found: 1, span: Span { start: 22, end: 26, line: 1, column: 23 } };
+        assert_eq!(error.render(source), "\
+error: expected 2 values in row, found 1
+ --> line 1, column 23
+  |
+1 | insert into t values (1,
+  |                       ^^");
+    }
+
+    #[test]
+    fn display_lists_alternatives() {
+        let error = ParseError::UnexpectedToken {
+            expected: vec![TokenKind::Join, TokenKind::On, TokenKind::Using],
+            found: TokenKind::Eof,
+            span: Span::default(),
+        };
+        assert_eq!(error.to_string(), "expected Join, On or Using, found Eof");
+
+        let error = ParseError::ExpectedKeyword { expected: vec!["KEY"], found: TokenKind::RParen, span: Span::default() };
+        assert_eq!(error.to_string(), "expected KEY, found RParen");
+    }
+
+    #[test]
+    fn display_messages() {
+        let span = Span::default();
+        for (error, expected) in [
+            (ParseError::ExpectedExpression { found: TokenKind::From, span }, "expected an expression, found From"),
+            (ParseError::UnknownStatement { found: TokenKind::RParen, span }, "unknown statement starting with RParen"),
+            (ParseError::UnterminatedLiteral { span }, "unterminated string literal"),
+            (ParseError::EmptyIdentifier { span }, "quoted identifier is empty"),
+            (
+                ParseError::NumberOutOfRange { kind: TokenKind::Int, literal: "-9223372036854775809".to_string(), span },
+                "integer literal -9223372036854775809 is out of range",
+            ),
+            (
+                ParseError::NumberOutOfRange { kind: TokenKind::Float, literal: "1e999".to_string(), span },
+                "float literal 1e999 is out of range",
+            ),
+            (ParseError::InvalidDecimal { literal: "1.2.3".to_string(), span }, "invalid decimal literal '1.2.3'"),
+            (ParseError::RowWidthMismatch { expected: 2, found: 1, span }, "expected 2 values in row, found 1"),
+            (ParseError::UnknownDataType { name: "blob".to_string(), span }, "unknown data type blob"),
+            (ParseError::InvalidTypeParameter { literal: "x".to_string(), span }, "invalid type parameter x"),
+            (ParseError::NotCallable { span }, "only named functions can be called"),
+        ] {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+}
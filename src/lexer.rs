@@ -1,11 +1,15 @@
 use std::collections::HashMap;
 
+use crate::error::ParseError;
+
 #[derive(PartialOrd, PartialEq, Debug, Copy, Clone, Eq, Hash)]
 pub enum TokenKind {
     Illegal,
     Eof,
     Identifier,
     Int,
+    Float,
+    String,
     Equals,
     NotEq,
     Plus,
@@ -15,7 +19,11 @@ pub enum TokenKind {
     Slash,
     Lt,
     Gt,
+    LtEq,
+    GtEq,
     Comma,
+    Dot,
+    DoubleColon,
     Semicolon,
     LParen,
     RParen,
@@ -30,12 +38,90 @@ pub enum TokenKind {
     Where,
     Values,
     Into,
+    And,
+    Or,
+    Not,
+    Set,
+    Create,
+    Null,
+    Primary,
+    Default,
+    If,
+    Exists,
+    Drop,
+    Alter,
+    As,
+    Join,
+    Inner,
+    Left,
+    Right,
+    Full,
+    Outer,
+    Cross,
+    On,
+    Using,
+    Distinct,
+    Group,
+    By,
+    Having,
+    Order,
+    Asc,
+    Desc,
+    Limit,
+    Offset,
+    In,
+    With,
+    Recursive,
+    Union,
+    Intersect,
+    Except,
+    All,
+    True,
+    False,
+    Case,
+    When,
+    Then,
+    Else,
+    Between,
+    Like,
+    Is,
+    Cast,
+}
+
+/// A range of the source text. `start` and `end` are byte offsets, `end` being exclusive, while `line`
+/// and `column` locate the first character and count from one.
+#[derive(PartialEq, Debug, Copy, Clone, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Returns the span running from the start of this one to the end of `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span { end: other.end, ..self }
+    }
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        Span { start: 0, end: 0, line: 1, column: 1 }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Token {
     pub kind: TokenKind,
     pub literal: String,
+    pub span: Span,
+}
+
+impl Token {
+    pub fn new(kind: TokenKind, literal: String) -> Self {
+        Token { kind, literal, span: Span::default() }
+    }
 }
 
 pub fn lookup_identifier(s: &str) -> TokenKind {
@@ -49,28 +135,88 @@ pub fn lookup_identifier(s: &str) -> TokenKind {
         ("WHERE", TokenKind::Where),
         ("VALUES", TokenKind::Values),
         ("INTO", TokenKind::Into),
+        ("AND", TokenKind::And),
+        ("OR", TokenKind::Or),
+        ("NOT", TokenKind::Not),
+        ("SET", TokenKind::Set),
+        ("CREATE", TokenKind::Create),
+        ("NULL", TokenKind::Null),
+        ("PRIMARY", TokenKind::Primary),
+        ("DEFAULT", TokenKind::Default),
+        ("IF", TokenKind::If),
+        ("EXISTS", TokenKind::Exists),
+        ("DROP", TokenKind::Drop),
+        ("ALTER", TokenKind::Alter),
+        ("AS", TokenKind::As),
+        ("JOIN", TokenKind::Join),
+        ("INNER", TokenKind::Inner),
+        ("LEFT", TokenKind::Left),
+        ("RIGHT", TokenKind::Right),
+        ("FULL", TokenKind::Full),
+        ("OUTER", TokenKind::Outer),
+        ("CROSS", TokenKind::Cross),
+        ("ON", TokenKind::On),
+        ("USING", TokenKind::Using),
+        ("DISTINCT", TokenKind::Distinct),
+        ("GROUP", TokenKind::Group),
+        ("BY", TokenKind::By),
+        ("HAVING", TokenKind::Having),
+        ("ORDER", TokenKind::Order),
+        ("ASC", TokenKind::Asc),
+        ("DESC", TokenKind::Desc),
+        ("LIMIT", TokenKind::Limit),
+        ("OFFSET", TokenKind::Offset),
+        ("IN", TokenKind::In),
+        ("WITH", TokenKind::With),
+        ("RECURSIVE", TokenKind::Recursive),
+        ("UNION", TokenKind::Union),
+        ("INTERSECT", TokenKind::Intersect),
+        ("EXCEPT", TokenKind::Except),
+        ("ALL", TokenKind::All),
+        ("TRUE", TokenKind::True),
+        ("FALSE", TokenKind::False),
+        ("CASE", TokenKind::Case),
+        ("WHEN", TokenKind::When),
+        ("THEN", TokenKind::Then),
+        ("ELSE", TokenKind::Else),
+        ("BETWEEN", TokenKind::Between),
+        ("LIKE", TokenKind::Like),
+        ("IS", TokenKind::Is),
+        ("CAST", TokenKind::Cast),
     ]);
 
     keywords.get(s.to_uppercase().as_str())
-        .or(Some(&TokenKind::Identifier))
-        .unwrap()
-        .clone()
+        .copied()
+        .unwrap_or(TokenKind::Identifier)
 }
 
+#[derive(Clone)]
 pub struct Lexer {
-    input: String,
+    input: Vec<char>,
     position: usize,
     read_position: usize,
     ch: Option<char>,
+    // location of `ch` in the source
+    offset: usize,
+    line: usize,
+    column: usize,
+    // where the token currently being read starts
+    token_start: Span,
+    errors: Vec<ParseError>,
 }
 
 impl Lexer {
     pub fn new(input: &str) -> Self {
         let mut lexer = Lexer {
-            input: input.to_string(),
+            input: input.chars().collect(),
             position: 0,
             read_position: 0,
             ch: None,
+            offset: 0,
+            line: 1,
+            column: 1,
+            token_start: Span::default(),
+            errors: vec![],
         };
 
         lexer.read_char();
@@ -78,74 +224,138 @@ impl Lexer {
     }
 
     fn read_char(&mut self) {
-        if self.read_position >= self.input.len() {
-            self.ch = Some('\0')
-        } else {
-            self.ch = self.input.chars().nth(self.read_position);
+        // step the location past the character being left behind
+        if self.read_position > 0 {
+            if let Some(&previous) = self.input.get(self.position) {
+                self.offset += previous.len_utf8();
+                if previous == '\n' {
+                    self.line += 1;
+                    self.column = 1;
+                } else {
+                    self.column += 1;
+                }
+            }
         }
+
+        self.ch = Some(self.input.get(self.read_position).copied().unwrap_or('\0'));
         self.position = self.read_position;
         self.read_position += 1
     }
 
     pub fn next_token(&mut self) -> Token {
-        let token: Token;
-
         self.eat_ws();
 
+        self.token_start = Span { start: self.offset, end: self.offset, line: self.line, column: self.column };
+        let mut token = self.read_token();
+        token.span = self.token_span();
+        token
+    }
+
+    /// The span from the start of the current token up to the current character.
+    fn token_span(&self) -> Span {
+        Span { end: self.offset, ..self.token_start }
+    }
+
+    fn read_token(&mut self) -> Token {
+        let token: Token;
+
         match self.ch {
             Some('=') => {
-                token = Token { kind: TokenKind::Equals, literal: "=".to_string() };
+                token = Token::new(TokenKind::Equals, "=".to_string());
             }
             Some(';') => {
-                token = Token { kind: TokenKind::Semicolon, literal: ";".to_string() };
+                token = Token::new(TokenKind::Semicolon, ";".to_string());
             }
             Some(',') => {
-                token = Token { kind: TokenKind::Comma, literal: ",".to_string() };
+                token = Token::new(TokenKind::Comma, ",".to_string());
+            }
+            Some('.') if !self.peek_char().is_some_and(|c| c.is_ascii_digit()) => {
+                token = Token::new(TokenKind::Dot, ".".to_string());
+            }
+            Some(':') if self.peek_char() == Some(':') => {
+                self.read_char();
+                token = Token::new(TokenKind::DoubleColon, "::".to_string());
             }
             Some('(') => {
-                token = Token { kind: TokenKind::LParen, literal: "(".to_string() };
+                token = Token::new(TokenKind::LParen, "(".to_string());
             }
             Some(')') => {
-                token = Token { kind: TokenKind::RParen, literal: ")".to_string() };
+                token = Token::new(TokenKind::RParen, ")".to_string());
             }
             Some('{') => {
-                token = Token { kind: TokenKind::LBrace, literal: "{".to_string() };
+                token = Token::new(TokenKind::LBrace, "{".to_string());
             }
             Some('}') => {
-                token = Token { kind: TokenKind::RBrace, literal: "}".to_string() };
+                token = Token::new(TokenKind::RBrace, "}".to_string());
             }
             Some('+') => {
-                token = Token { kind: TokenKind::Plus, literal: "+".to_string() };
+                token = Token::new(TokenKind::Plus, "+".to_string());
             }
             Some('-') => {
-                token = Token { kind: TokenKind::Minus, literal: "-".to_string() };
+                token = Token::new(TokenKind::Minus, "-".to_string());
             }
             Some('!') => {
                 let peek = self.peek_char();
                 token = if let Some('=') = peek {
                     self.read_char();
-                    Token { kind: TokenKind::NotEq, literal: "!=".to_string() }
+                    Token::new(TokenKind::NotEq, "!=".to_string())
                 } else {
-                    Token { kind: TokenKind::Bang, literal: "!".to_string() }
+                    Token::new(TokenKind::Bang, "!".to_string())
                 };
             }
             Some('*') => {
-                token = Token { kind: TokenKind::Asterisk, literal: "*".to_string() };
+                token = Token::new(TokenKind::Asterisk, "*".to_string());
             }
             Some('/') => {
-                token = Token { kind: TokenKind::Slash, literal: "/".to_string() };
+                token = Token::new(TokenKind::Slash, "/".to_string());
             }
             Some('<') => {
-                token = Token { kind: TokenKind::Lt, literal: "<".to_string() };
+                let peek = self.peek_char();
+                token = if let Some('=') = peek {
+                    self.read_char();
+                    Token::new(TokenKind::LtEq, "<=".to_string())
+                } else {
+                    Token::new(TokenKind::Lt, "<".to_string())
+                };
             }
             Some('>') => {
-                token = Token { kind: TokenKind::Gt, literal: ">".to_string() };
+                let peek = self.peek_char();
+                token = if let Some('=') = peek {
+                    self.read_char();
+                    Token::new(TokenKind::GtEq, ">=".to_string())
+                } else {
+                    Token::new(TokenKind::Gt, ">".to_string())
+                };
+            }
+            Some('\'') => {
+                token = match self.read_quoted('\'') {
+                    Some(literal) => Token::new(TokenKind::String, literal),
+                    None => {
+                        self.errors.push(ParseError::UnterminatedLiteral { span: self.token_span() });
+                        return Token::new(TokenKind::Illegal, "".to_string());
+                    }
+                };
+            }
+            Some('"') => {
+                // the literal keeps its quotes so the parser can tell the identifier was quoted
+                token = match self.read_quoted('"') {
+                    Some(name) if name.is_empty() => {
+                        self.read_char();
+                        self.errors.push(ParseError::EmptyIdentifier { span: self.token_span() });
+                        return Token::new(TokenKind::Illegal, "".to_string());
+                    }
+                    Some(name) => Token::new(TokenKind::Identifier, format!("\"{}\"", name.replace('"', "\"\""))),
+                    None => {
+                        self.errors.push(ParseError::UnterminatedLiteral { span: self.token_span() });
+                        return Token::new(TokenKind::Illegal, "".to_string());
+                    }
+                };
             }
             Some('\0') => {
-                token = Token { kind: TokenKind::Eof, literal: "\0".to_string() };
+                token = Token::new(TokenKind::Eof, "\0".to_string());
             }
             Some(t) => {
-                if t.is_alphabetic() {
+                if t.is_alphabetic() || t == '_' {
                     let literal = self.read_identifier();
                     let kind = lookup_identifier(&literal);
                     let literal = if kind == TokenKind::Identifier {
@@ -154,28 +364,27 @@ impl Lexer {
                         literal.to_uppercase()
                     };
 
-                    return Token { kind, literal };
+                    return Token::new(kind, literal);
 
-                } else if t.is_digit(10) {
-                    let literal = self.read_number();
-                    return Token { kind: TokenKind::Int, literal };
+                } else if t.is_ascii_digit() || (t == '.' && self.peek_char().is_some_and(|c| c.is_ascii_digit())) {
+                    return self.read_number();
                 } else {
-                    token = Token { kind: TokenKind::Illegal, literal: "".to_string() };
+                    token = Token::new(TokenKind::Illegal, "".to_string());
                 }
             }
             None => {
-                return Token { kind: TokenKind::Illegal, literal: "".to_string() };
+                return Token::new(TokenKind::Illegal, "".to_string());
             }
         }
 
         self.read_char();
-        return token;
+        token
     }
 
     fn read_identifier(&mut self) -> String {
         let mut identifier = vec![];
 
-        while self.ch.unwrap().is_alphanumeric() {
+        while self.ch.unwrap().is_alphanumeric() || self.ch == Some('_') {
             identifier.push(self.ch.unwrap());
             self.read_char();
         }
@@ -183,29 +392,91 @@ impl Lexer {
         identifier.into_iter().collect()
     }
 
+    /// Reads text enclosed in `quote` starting at the opening quote, leaving the closing quote as the
+    /// current character. A doubled quote stands for one literal quote. Returns `None` if the input
+    /// ends before the text is closed.
+    fn read_quoted(&mut self, quote: char) -> Option<String> {
+        let mut string = vec![];
+
+        loop {
+            self.read_char();
+            match self.ch {
+                Some(c) if c == quote && self.peek_char() == Some(quote) => {
+                    string.push(quote);
+                    self.read_char();
+                }
+                Some(c) if c == quote => break,
+                Some('\0') if self.position >= self.input.len() => return None,
+                Some(c) => string.push(c),
+                None => return None,
+            }
+        }
+
+        Some(string.into_iter().collect())
+    }
+
+    /// Hands over the errors found while tokenizing so far.
+    pub fn take_errors(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.errors)
+    }
+
     fn eat_ws(&mut self) {
         while self.ch.unwrap().is_whitespace() {
             self.read_char();
         }
     }
 
-    fn read_number(&mut self) -> String {
+    /// Reads an integer or a float such as `1.5`, `.5` or `1e-3`.
+    fn read_number(&mut self) -> Token {
         let mut number = vec![];
-        while self.ch.unwrap().is_digit(10) {
-            number.push(self.ch.unwrap());
+        let mut kind = TokenKind::Int;
+
+        self.read_digits(&mut number);
+
+        if self.ch == Some('.') && self.peek_char().is_some_and(|c| c.is_ascii_digit()) {
+            kind = TokenKind::Float;
+            number.push('.');
             self.read_char();
+            self.read_digits(&mut number);
+        }
+
+        if let Some('e' | 'E') = self.ch {
+            let exponent_follows = match self.peek_char() {
+                Some('+' | '-') => self.peek_nth_char(2).is_some_and(|c| c.is_ascii_digit()),
+                Some(c) => c.is_ascii_digit(),
+                None => false,
+            };
+
+            if exponent_follows {
+                kind = TokenKind::Float;
+                number.push('e');
+                self.read_char();
+                if let Some(sign @ ('+' | '-')) = self.ch {
+                    number.push(sign);
+                    self.read_char();
+                }
+                self.read_digits(&mut number);
+                return Token::new(kind, number.into_iter().collect());
+            }
         }
 
-        number.into_iter().collect()
+        Token::new(kind, number.into_iter().collect())
     }
 
-    fn peek_char(&self) -> Option<char> {
-        if self.read_position >= self.input.len() {
-            None
-        } else {
-            self.input.chars().nth(self.read_position)
+    fn read_digits(&mut self, number: &mut Vec<char>) {
+        while self.ch.unwrap().is_ascii_digit() {
+            number.push(self.ch.unwrap());
+            self.read_char();
         }
     }
+
+    fn peek_char(&self) -> Option<char> {
+        self.peek_nth_char(1)
+    }
+
+    fn peek_nth_char(&self, n: usize) -> Option<char> {
+        self.input.get(self.position + n).copied()
+    }
 }
 
 #[cfg(test)]
@@ -217,45 +488,146 @@ mod tests {
         let input = r#"=+(){},;
         SELECT UPDATE INSERT DELETE 12 23      1234
 
-        1 + 2 = 1000 1 > < !12 FROM TABLE into Where values != = abcd, 1122!"#;
+        1 + 2 = 1000 1 > < !12 FROM TABLE into Where values != = abcd, 1122!
+        <= >= < > and OR not set
+        create null primary key default if exists
+        drop alter add column rename to
+        'bob' 'it''s' '' 'a, b'
+        1.5 .5 1e-3 2E+10 3e7 1.e 2e
+        t.* t.5 as
+        snake_case _x1 "Mixed Case" "select" "a""b"
+        join inner left right full outer cross on using
+        distinct group by having
+        order asc desc nulls first last limit offset in
+        with recursive union intersect except all
+        true false case when then else end between like escape is
+        cast ::"#;
         let mut lexer = Lexer::new(input);
         let tests = vec![
-            Token { kind: TokenKind::Equals, literal: "=".to_string() },
-            Token { kind: TokenKind::Plus, literal: "+".to_string() },
-            Token { kind: TokenKind::LParen, literal: "(".to_string() },
-            Token { kind: TokenKind::RParen, literal: ")".to_string() },
-            Token { kind: TokenKind::LBrace, literal: "{".to_string() },
-            Token { kind: TokenKind::RBrace, literal: "}".to_string() },
-            Token { kind: TokenKind::Comma, literal: ",".to_string() },
-            Token { kind: TokenKind::Semicolon, literal: ";".to_string() },
-            Token { kind: TokenKind::Select, literal: "SELECT".to_string() },
-            Token { kind: TokenKind::Update, literal: "UPDATE".to_string() },
-            Token { kind: TokenKind::Insert, literal: "INSERT".to_string() },
-            Token { kind: TokenKind::Delete, literal: "DELETE".to_string() },
-            Token { kind: TokenKind::Int, literal: "12".to_string() },
-            Token { kind: TokenKind::Int, literal: "23".to_string() },
-            Token { kind: TokenKind::Int, literal: "1234".to_string() },
-            Token { kind: TokenKind::Int, literal: "1".to_string() },
-            Token { kind: TokenKind::Plus, literal: "+".to_string() },
-            Token { kind: TokenKind::Int, literal: "2".to_string() },
-            Token { kind: TokenKind::Equals, literal: "=".to_string() },
-            Token { kind: TokenKind::Int, literal: "1000".to_string() },
-            Token { kind: TokenKind::Int, literal: "1".to_string() },
-            Token { kind: TokenKind::Gt, literal: ">".to_string() },
-            Token { kind: TokenKind::Lt, literal: "<".to_string() },
-            Token { kind: TokenKind::Bang, literal: "!".to_string() },
-            Token { kind: TokenKind::Int, literal: "12".to_string() },
-            Token { kind: TokenKind::From, literal: "FROM".to_string() },
-            Token { kind: TokenKind::Table, literal: "TABLE".to_string() },
-            Token { kind: TokenKind::Into, literal: "INTO".to_string() },
-            Token { kind: TokenKind::Where, literal: "WHERE".to_string() },
-            Token { kind: TokenKind::Values, literal: "VALUES".to_string() },
-            Token { kind: TokenKind::NotEq, literal: "!=".to_string() },
-            Token { kind: TokenKind::Equals, literal: "=".to_string() },
-            Token { kind: TokenKind::Identifier, literal: "abcd".to_string() },
-            Token { kind: TokenKind::Comma, literal: ",".to_string() },
-            Token { kind: TokenKind::Int, literal: "1122".to_string() },
-            Token { kind: TokenKind::Bang, literal: "!".to_string() },
+            Token::new(TokenKind::Equals, "=".to_string()),
+            Token::new(TokenKind::Plus, "+".to_string()),
+            Token::new(TokenKind::LParen, "(".to_string()),
+            Token::new(TokenKind::RParen, ")".to_string()),
+            Token::new(TokenKind::LBrace, "{".to_string()),
+            Token::new(TokenKind::RBrace, "}".to_string()),
+            Token::new(TokenKind::Comma, ",".to_string()),
+            Token::new(TokenKind::Semicolon, ";".to_string()),
+            Token::new(TokenKind::Select, "SELECT".to_string()),
+            Token::new(TokenKind::Update, "UPDATE".to_string()),
+            Token::new(TokenKind::Insert, "INSERT".to_string()),
+            Token::new(TokenKind::Delete, "DELETE".to_string()),
+            Token::new(TokenKind::Int, "12".to_string()),
+            Token::new(TokenKind::Int, "23".to_string()),
+            Token::new(TokenKind::Int, "1234".to_string()),
+            Token::new(TokenKind::Int, "1".to_string()),
+            Token::new(TokenKind::Plus, "+".to_string()),
+            Token::new(TokenKind::Int, "2".to_string()),
+            Token::new(TokenKind::Equals, "=".to_string()),
+            Token::new(TokenKind::Int, "1000".to_string()),
+            Token::new(TokenKind::Int, "1".to_string()),
+            Token::new(TokenKind::Gt, ">".to_string()),
+            Token::new(TokenKind::Lt, "<".to_string()),
+            Token::new(TokenKind::Bang, "!".to_string()),
+            Token::new(TokenKind::Int, "12".to_string()),
+            Token::new(TokenKind::From, "FROM".to_string()),
+            Token::new(TokenKind::Table, "TABLE".to_string()),
+            Token::new(TokenKind::Into, "INTO".to_string()),
+            Token::new(TokenKind::Where, "WHERE".to_string()),
+            Token::new(TokenKind::Values, "VALUES".to_string()),
+            Token::new(TokenKind::NotEq, "!=".to_string()),
+            Token::new(TokenKind::Equals, "=".to_string()),
+            Token::new(TokenKind::Identifier, "abcd".to_string()),
+            Token::new(TokenKind::Comma, ",".to_string()),
+            Token::new(TokenKind::Int, "1122".to_string()),
+            Token::new(TokenKind::Bang, "!".to_string()),
+            Token::new(TokenKind::LtEq, "<=".to_string()),
+            Token::new(TokenKind::GtEq, ">=".to_string()),
+            Token::new(TokenKind::Lt, "<".to_string()),
+            Token::new(TokenKind::Gt, ">".to_string()),
+            Token::new(TokenKind::And, "AND".to_string()),
+            Token::new(TokenKind::Or, "OR".to_string()),
+            Token::new(TokenKind::Not, "NOT".to_string()),
+            Token::new(TokenKind::Set, "SET".to_string()),
+            Token::new(TokenKind::Create, "CREATE".to_string()),
+            Token::new(TokenKind::Null, "NULL".to_string()),
+            Token::new(TokenKind::Primary, "PRIMARY".to_string()),
+            Token::new(TokenKind::Identifier, "key".to_string()),
+            Token::new(TokenKind::Default, "DEFAULT".to_string()),
+            Token::new(TokenKind::If, "IF".to_string()),
+            Token::new(TokenKind::Exists, "EXISTS".to_string()),
+            Token::new(TokenKind::Drop, "DROP".to_string()),
+            Token::new(TokenKind::Alter, "ALTER".to_string()),
+            Token::new(TokenKind::Identifier, "add".to_string()),
+            Token::new(TokenKind::Identifier, "column".to_string()),
+            Token::new(TokenKind::Identifier, "rename".to_string()),
+            Token::new(TokenKind::Identifier, "to".to_string()),
+            Token::new(TokenKind::String, "bob".to_string()),
+            Token::new(TokenKind::String, "it's".to_string()),
+            Token::new(TokenKind::String, "".to_string()),
+            Token::new(TokenKind::String, "a, b".to_string()),
+            Token::new(TokenKind::Float, "1.5".to_string()),
+            Token::new(TokenKind::Float, ".5".to_string()),
+            Token::new(TokenKind::Float, "1e-3".to_string()),
+            Token::new(TokenKind::Float, "2e+10".to_string()),
+            Token::new(TokenKind::Float, "3e7".to_string()),
+            Token::new(TokenKind::Int, "1".to_string()),
+            Token::new(TokenKind::Dot, ".".to_string()),
+            Token::new(TokenKind::Identifier, "e".to_string()),
+            Token::new(TokenKind::Int, "2".to_string()),
+            Token::new(TokenKind::Identifier, "e".to_string()),
+            Token::new(TokenKind::Identifier, "t".to_string()),
+            Token::new(TokenKind::Dot, ".".to_string()),
+            Token::new(TokenKind::Asterisk, "*".to_string()),
+            Token::new(TokenKind::Identifier, "t".to_string()),
+            Token::new(TokenKind::Float, ".5".to_string()),
+            Token::new(TokenKind::As, "AS".to_string()),
+            Token::new(TokenKind::Identifier, "snake_case".to_string()),
+            Token::new(TokenKind::Identifier, "_x1".to_string()),
+            Token::new(TokenKind::Identifier, "\"Mixed Case\"".to_string()),
+            Token::new(TokenKind::Identifier, "\"select\"".to_string()),
+            Token::new(TokenKind::Identifier, "\"a\"\"b\"".to_string()),
+            Token::new(TokenKind::Join, "JOIN".to_string()),
+            Token::new(TokenKind::Inner, "INNER".to_string()),
+            Token::new(TokenKind::Left, "LEFT".to_string()),
+            Token::new(TokenKind::Right, "RIGHT".to_string()),
+            Token::new(TokenKind::Full, "FULL".to_string()),
+            Token::new(TokenKind::Outer, "OUTER".to_string()),
+            Token::new(TokenKind::Cross, "CROSS".to_string()),
+            Token::new(TokenKind::On, "ON".to_string()),
+            Token::new(TokenKind::Using, "USING".to_string()),
+            Token::new(TokenKind::Distinct, "DISTINCT".to_string()),
+            Token::new(TokenKind::Group, "GROUP".to_string()),
+            Token::new(TokenKind::By, "BY".to_string()),
+            Token::new(TokenKind::Having, "HAVING".to_string()),
+            Token::new(TokenKind::Order, "ORDER".to_string()),
+            Token::new(TokenKind::Asc, "ASC".to_string()),
+            Token::new(TokenKind::Desc, "DESC".to_string()),
+            Token::new(TokenKind::Identifier, "nulls".to_string()),
+            Token::new(TokenKind::Identifier, "first".to_string()),
+            Token::new(TokenKind::Identifier, "last".to_string()),
+            Token::new(TokenKind::Limit, "LIMIT".to_string()),
+            Token::new(TokenKind::Offset, "OFFSET".to_string()),
+            Token::new(TokenKind::In, "IN".to_string()),
+            Token::new(TokenKind::With, "WITH".to_string()),
+            Token::new(TokenKind::Recursive, "RECURSIVE".to_string()),
+            Token::new(TokenKind::Union, "UNION".to_string()),
+            Token::new(TokenKind::Intersect, "INTERSECT".to_string()),
+            Token::new(TokenKind::Except, "EXCEPT".to_string()),
+            Token::new(TokenKind::All, "ALL".to_string()),
+            Token::new(TokenKind::True, "TRUE".to_string()),
+            Token::new(TokenKind::False, "FALSE".to_string()),
+            Token::new(TokenKind::Case, "CASE".to_string()),
+            Token::new(TokenKind::When, "WHEN".to_string()),
+            Token::new(TokenKind::Then, "THEN".to_string()),
+            Token::new(TokenKind::Else, "ELSE".to_string()),
+            Token::new(TokenKind::Identifier, "end".to_string()),
+            Token::new(TokenKind::Between, "BETWEEN".to_string()),
+            Token::new(TokenKind::Like, "LIKE".to_string()),
+            Token::new(TokenKind::Identifier, "escape".to_string()),
+            Token::new(TokenKind::Is, "IS".to_string()),
+            Token::new(TokenKind::Cast, "CAST".to_string()),
+            Token::new(TokenKind::DoubleColon, "::".to_string()),
+            Token::new(TokenKind::Eof, "\0".to_string()),
         ];
 
         for test in tests {
@@ -264,4 +636,59 @@ mod tests {
             assert_eq!(token.literal, test.literal, "failed literal check, found {:?}, expected {:?}", token.literal, test.literal);
         }
     }
+
+    #[test]
+    fn unterminated_string() {
+        for input in ["'abc", "'", "'it''s", "select 'abc''", "\"abc", "select \"a\"\""] {
+            let mut lexer = Lexer::new(input);
+            let mut token = lexer.next_token();
+            while token.kind != TokenKind::Illegal {
+                assert_ne!(token.kind, TokenKind::Eof, "{} should not reach Eof", input);
+                token = lexer.next_token();
+            }
+            let errors = lexer.take_errors();
+            assert_eq!(errors.len(), 1);
+            assert!(matches!(errors[0], ParseError::UnterminatedLiteral { .. }));
+            assert_eq!(errors[0].span().end, input.len());
+            assert_eq!(lexer.next_token().kind, TokenKind::Eof);
+        }
+    }
+
+    #[test]
+    fn empty_quoted_identifier() {
+        let mut lexer = Lexer::new("select \"\" from");
+        assert_eq!(lexer.next_token().kind, TokenKind::Select);
+        assert_eq!(lexer.next_token().kind, TokenKind::Illegal);
+        assert_eq!(lexer.take_errors(), vec![ParseError::EmptyIdentifier { span: Span { start: 7, end: 9, line: 1, column: 8 } }]);
+        assert_eq!(lexer.next_token().kind, TokenKind::From);
+    }
+
+    #[test]
+    fn token_spans() {
+        let input = "select naïve,\n  'x''y' >= 1.5\n\n";
+        let mut lexer = Lexer::new(input);
+        let tests = vec![
+            (TokenKind::Select, Span { start: 0, end: 6, line: 1, column: 1 }),
+            (TokenKind::Identifier, Span { start: 7, end: 13, line: 1, column: 8 }),
+            (TokenKind::Comma, Span { start: 13, end: 14, line: 1, column: 13 }),
+            (TokenKind::String, Span { start: 17, end: 23, line: 2, column: 3 }),
+            (TokenKind::GtEq, Span { start: 24, end: 26, line: 2, column: 10 }),
+            (TokenKind::Float, Span { start: 27, end: 30, line: 2, column: 13 }),
+            (TokenKind::Eof, Span { start: 32, end: 32, line: 4, column: 1 }),
+        ];
+
+        for (kind, span) in tests {
+            let token = lexer.next_token();
+            assert_eq!(token.kind, kind);
+            assert_eq!(token.span, span, "wrong span for {:?}", token.kind);
+        }
+
+        let mut lexer = Lexer::new(input);
+        lexer.next_token();
+        let span = lexer.next_token().span;
+        assert_eq!(&input[span.start..span.end], "naïve");
+
+        assert_eq!(Span { start: 0, end: 6, line: 1, column: 1 }.to(Span { start: 27, end: 30, line: 2, column: 13 }),
+                   Span { start: 0, end: 30, line: 1, column: 1 });
+    }
 }
@@ -1,8 +1,3 @@
-mod lexer;
-mod parser;
-mod ast;
-
 fn main() {
     println!("Hello, world!");
 }
-
@@ -1,263 +1,3427 @@
 use std::collections::HashMap;
 
+use crate::ast::alter_table_action::AlterTableAction;
+use crate::ast::alter_table_statement::AlterTableStatement;
+use crate::ast::assignment::Assignment;
+use crate::ast::between_expression::BetweenExpression;
+use crate::ast::boolean_literal::BooleanLiteral;
+use crate::ast::call_expression::{CallExpression, FunctionArgument};
+use crate::ast::case_expression::{CaseExpression, WhenClause};
+use crate::ast::cast_expression::CastExpression;
+use crate::ast::column_definition::ColumnDefinition;
+use crate::ast::compound_identifier::CompoundIdentifier;
+use crate::ast::create_table_statement::CreateTableStatement;
+use crate::ast::data_type::DataType;
+use crate::ast::decimal_literal::DecimalLiteral;
+use crate::ast::delete_statement::DeleteStatement;
+use crate::ast::drop_table_statement::DropTableStatement;
+use crate::ast::exists_expression::ExistsExpression;
 use crate::ast::expression::Expression;
+use crate::ast::float_literal::FloatLiteral;
+use crate::ast::from_clause::FromClause;
 use crate::ast::identifier::Identifier;
+use crate::ast::in_list::InList;
+use crate::ast::in_subquery::InSubquery;
+use crate::ast::infix_expression::InfixExpression;
+use crate::ast::insert_statement::InsertStatement;
+use crate::ast::order_by_expression::OrderByExpression;
 use crate::ast::int_literal::IntLiteral;
+use crate::ast::is_null_expression::IsNullExpression;
+use crate::ast::join::{Join, JoinConstraint, JoinKind};
+use crate::ast::like_expression::LikeExpression;
+use crate::ast::null_literal::NullLiteral;
+use crate::ast::operator::{BinaryOperator, UnaryOperator};
 use crate::ast::prefix_expression::PrefixExpression;
+use crate::ast::program::Program;
+use crate::ast::query::Query;
+use crate::ast::select_item::SelectItem;
 use crate::ast::select_statement::SelectStatement;
+use crate::ast::set_expression::{SetExpression, SetOperation, SetOperator};
 use crate::ast::statement::{ExpressionStatement, Statement};
-use crate::lexer::{Lexer, Token, TokenKind};
+use crate::ast::string_literal::StringLiteral;
+use crate::ast::subquery::Subquery;
+use crate::ast::table_reference::TableReference;
+use crate::ast::update_statement::UpdateStatement;
+use crate::ast::with_clause::{CommonTableExpression, WithClause};
+use crate::ast::Node;
+use crate::error::ParseError;
+use crate::lexer::{Lexer, Span, Token, TokenKind};
 
+#[derive(PartialOrd, PartialEq, Debug, Copy, Clone)]
 enum Precedence {
     Lowest = 1,
+    Or,
+    And,
+    Not,
+    Is,
     Equals,
     LessGreater,
+    // [NOT] IN, [NOT] BETWEEN and [NOT] LIKE
+    Predicate,
     Sum,
     Product,
     Prefix,
+    Cast,
+    Call,
 }
 
 type PrefixParser = fn(&mut Parser) -> Option<Expression>;
-type InfixParser = fn(&mut Parser, Expression) -> Expression;
+type InfixParser = fn(&mut Parser, Expression) -> Option<Expression>;
+
+fn precedence_of(kind: TokenKind) -> Precedence {
+    match kind {
+        TokenKind::Or => Precedence::Or,
+        TokenKind::And => Precedence::And,
+        TokenKind::Is => Precedence::Is,
+        TokenKind::Equals | TokenKind::NotEq => Precedence::Equals,
+        TokenKind::Lt | TokenKind::Gt | TokenKind::LtEq | TokenKind::GtEq => Precedence::LessGreater,
+        // as an infix operator NOT only ever starts a negated predicate such as NOT IN
+        TokenKind::In | TokenKind::Between | TokenKind::Like | TokenKind::Not => Precedence::Predicate,
+        TokenKind::Plus | TokenKind::Minus => Precedence::Sum,
+        TokenKind::Asterisk | TokenKind::Slash => Precedence::Product,
+        TokenKind::DoubleColon => Precedence::Cast,
+        TokenKind::LParen => Precedence::Call,
+        _ => Precedence::Lowest,
+    }
+}
 
 pub struct Parser {
     lexer: Lexer,
     current_token: Token,
     peek_token: Token,
-    errors: Vec<String>,
+    errors: Vec<ParseError>,
     prefix_parsers: HashMap<TokenKind, PrefixParser>,
     infix_parsers: HashMap<TokenKind, InfixParser>,
+    // the operators behind the tokens registered to parse_prefix_expression and parse_infix_expression
+    unary_operators: HashMap<TokenKind, UnaryOperator>,
+    binary_operators: HashMap<TokenKind, BinaryOperator>,
 }
 
 // Parsing functions
 impl Parser {
+    /// Parses a whole script of statements separated by semicolons. A statement that fails to parse is
+    /// skipped up to the next semicolon so the rest of the script is still checked, and every problem
+    /// found ends up in `errors`.
+    pub fn parse_program(&mut self) -> Program {
+        let mut statements = vec![];
+
+        loop {
+            // empty statements are allowed
+            while self.current_token_is(TokenKind::Semicolon) {
+                self.next_token();
+            }
+
+            if self.current_token_is(TokenKind::Eof) {
+                break;
+            }
+
+            match self.parse_statement() {
+                Some(statement) if self.peek_token_is(TokenKind::Semicolon) || self.peek_token_is(TokenKind::Eof) => {
+                    statements.push(statement);
+                    self.next_token();
+                }
+                Some(_) => {
+                    // trailing tokens mean the statement is not what was written, so it is dropped
+                    self.expect_peek(TokenKind::Semicolon);
+                    self.synchronize();
+                }
+                None => self.synchronize(),
+            }
+        }
+
+        Program { statements }
+    }
+
     pub fn parse_statement(&mut self) -> Option<Statement> {
         match self.current_token.kind {
-            TokenKind::Select => self.parse_select_statement(),
+            TokenKind::Select | TokenKind::With => Some(Statement::Query(Box::new(self.parse_query()?))),
+            TokenKind::LParen if self.parenthesized_query_follows() => Some(Statement::Query(Box::new(self.parse_query()?))),
+            TokenKind::Insert => self.parse_insert_statement(),
+            TokenKind::Update => self.parse_update_statement(),
+            TokenKind::Delete => self.parse_delete_statement(),
+            TokenKind::Create => self.parse_create_table_statement(),
+            TokenKind::Drop => self.parse_drop_table_statement(),
+            TokenKind::Alter => self.parse_alter_table_statement(),
+            kind if !self.prefix_parsers.contains_key(&kind) => {
+                self.errors.push(ParseError::UnknownStatement { found: kind, span: self.current_token.span });
+                None
+            }
             _ => self.parse_expression_statement(),
         }
     }
 
-    fn parse_expression_statement(&mut self) -> Option<Statement> {
-        let token = self.current_token.clone();
-        let expression = self.parse_expression(Precedence::Lowest)?;
-        let statement = Statement::Expr(ExpressionStatement { token, expression });
-        Some(statement)
+    fn parse_expression_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+        let expression = self.parse_expression(Precedence::Lowest)?;
+        let span = expression.span();
+        let statement = Statement::Expr(ExpressionStatement { token, span, expression });
+        Some(statement)
+    }
+
+    fn parse_expression(&mut self, precedence: Precedence) -> Option<Expression> {
+        let prefix = match self.prefix_parsers.get(&self.current_token.kind) {
+            Some(prefix) => *prefix,
+            None => {
+                let (found, span) = (self.current_token.kind, self.current_token.span);
+                self.errors.push(ParseError::ExpectedExpression { found, span });
+                return None;
+            }
+        };
+
+        let left = prefix(self)?;
+        self.parse_infix_expressions(left, precedence)
+    }
+
+    /// Continues an expression whose leftmost operand has already been parsed.
+    fn parse_infix_expressions(&mut self, mut left: Expression, precedence: Precedence) -> Option<Expression> {
+        // keep folding operators into the left side while they bind tighter than the caller
+        while !self.peek_token_is(TokenKind::Semicolon) && precedence < self.peek_precedence() {
+            let infix = match self.infix_parsers.get(&self.peek_token.kind) {
+                Some(infix) => *infix,
+                None => return Some(left),
+            };
+
+            self.next_token();
+            left = infix(self, left)?;
+        }
+
+        Some(left)
+    }
+
+    /// Parses a query starting at its first token: SELECT, WITH or the parenthesis around its first
+    /// operand. Subqueries come through here as well, so the query is returned bare rather than
+    /// wrapped in a `Statement`.
+    fn parse_query(&mut self) -> Option<Query> {
+        let token = self.current_token.clone();
+
+        let with = if self.current_token_is(TokenKind::With) {
+            let with = self.parse_with_clause()?;
+            match self.peek_token.kind {
+                TokenKind::Select | TokenKind::LParen => self.next_token(),
+                found => {
+                    self.errors.push(ParseError::UnexpectedToken {
+                        expected: vec![TokenKind::Select, TokenKind::LParen],
+                        found,
+                        span: self.peek_token.span,
+                    });
+                    return None;
+                }
+            }
+            Some(with)
+        } else {
+            None
+        };
+
+        let body = self.parse_set_expression()?;
+
+        let mut order_by = vec![];
+        if self.peek_token_is(TokenKind::Order) {
+            self.next_token();
+            if !self.expect_peek(TokenKind::By) {
+                return None;
+            }
+            order_by.push(self.parse_order_by_expression()?);
+            while self.peek_token_is(TokenKind::Comma) {
+                self.next_token();
+                order_by.push(self.parse_order_by_expression()?);
+            }
+        }
+
+        let mut limit = None;
+        if self.peek_token_is(TokenKind::Limit) {
+            self.next_token();
+            self.next_token();
+            limit = Some(self.parse_expression(Precedence::Lowest)?);
+        }
+
+        let mut offset = None;
+        if self.peek_token_is(TokenKind::Offset) {
+            self.next_token();
+            self.next_token();
+            offset = Some(self.parse_expression(Precedence::Lowest)?);
+        }
+
+        let span = self.span_from(token.span);
+        Some(Query { token, span, with, body, order_by, limit, offset })
+    }
+
+    /// Parses UNION and EXCEPT, which share a precedence and associate to the left.
+    fn parse_set_expression(&mut self) -> Option<SetExpression> {
+        let mut left = self.parse_intersection()?;
+
+        while self.peek_token_is(TokenKind::Union) || self.peek_token_is(TokenKind::Except) {
+            self.next_token();
+            left = self.parse_set_operation(left, Parser::parse_intersection)?;
+        }
+
+        Some(left)
+    }
+
+    /// INTERSECT binds tighter than UNION and EXCEPT.
+    fn parse_intersection(&mut self) -> Option<SetExpression> {
+        let mut left = self.parse_set_operand()?;
+
+        while self.peek_token_is(TokenKind::Intersect) {
+            self.next_token();
+            left = self.parse_set_operation(left, Parser::parse_set_operand)?;
+        }
+
+        Some(left)
+    }
+
+    /// Parses the rest of a set operation with its operator as the current token.
+    fn parse_set_operation(
+        &mut self,
+        left: SetExpression,
+        parse_right: fn(&mut Parser) -> Option<SetExpression>,
+    ) -> Option<SetExpression> {
+        let token = self.current_token.clone();
+        let operator = match token.kind {
+            TokenKind::Union => SetOperator::Union,
+            TokenKind::Intersect => SetOperator::Intersect,
+            _ => SetOperator::Except,
+        };
+
+        let all = self.peek_token_is(TokenKind::All);
+        if all {
+            self.next_token();
+        }
+
+        self.next_token();
+        let right = parse_right(self)?;
+        let span = left.span().to(right.span());
+        Some(SetExpression::Operation(SetOperation {
+            token,
+            span,
+            operator,
+            all,
+            left: Box::new(left),
+            right: Box::new(right),
+        }))
+    }
+
+    fn parse_set_operand(&mut self) -> Option<SetExpression> {
+        match self.current_token.kind {
+            TokenKind::Select => Some(SetExpression::Select(Box::new(self.parse_select()?))),
+            TokenKind::LParen => Some(SetExpression::Query(Box::new(self.parse_parenthesized_query()?))),
+            found => {
+                self.errors.push(ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::Select, TokenKind::LParen],
+                    found,
+                    span: self.current_token.span,
+                });
+                None
+            }
+        }
+    }
+
+    fn parse_with_clause(&mut self) -> Option<WithClause> {
+        let token = self.current_token.clone();
+
+        let recursive = self.peek_token_is(TokenKind::Recursive);
+        if recursive {
+            self.next_token();
+        }
+
+        let mut ctes = vec![self.parse_common_table_expression()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            ctes.push(self.parse_common_table_expression()?);
+        }
+
+        let span = self.span_from(token.span);
+        Some(WithClause { token, span, recursive, ctes })
+    }
+
+    fn parse_common_table_expression(&mut self) -> Option<CommonTableExpression> {
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+        let name = self.parse_identifier()?;
+
+        let mut columns = vec![];
+        if self.peek_token_is(TokenKind::LParen) {
+            self.next_token();
+            columns = self.parse_expression_list()?;
+            if !self.expect_peek(TokenKind::RParen) {
+                return None;
+            }
+        }
+
+        if !self.expect_peek(TokenKind::As) || !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        let query = Box::new(self.parse_parenthesized_query()?);
+        let span = self.span_from(name.span());
+        Some(CommonTableExpression { name, columns, query, span })
+    }
+
+    fn parse_select(&mut self) -> Option<SelectStatement> {
+        let token = self.current_token.clone();
+
+        let mut projection = vec![self.parse_select_item()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            projection.push(self.parse_select_item()?);
+        }
+
+        if !self.expect_peek(TokenKind::From) {
+            return None;
+        }
+
+        let from = self.parse_from_clause()?;
+        let where_clause = self.parse_where_clause()?;
+
+        let mut group_by = vec![];
+        if self.peek_token_is(TokenKind::Group) {
+            self.next_token();
+            if !self.expect_peek(TokenKind::By) {
+                return None;
+            }
+            loop {
+                self.next_token();
+                group_by.push(self.parse_expression(Precedence::Lowest)?);
+                if !self.peek_token_is(TokenKind::Comma) {
+                    break;
+                }
+                self.next_token();
+            }
+        }
+
+        let mut having = None;
+        if self.peek_token_is(TokenKind::Having) {
+            self.next_token();
+            self.next_token();
+            having = Some(self.parse_expression(Precedence::Lowest)?);
+        }
+
+        let span = self.span_from(token.span);
+        Some(SelectStatement { token, span, from, projection, where_clause, group_by, having })
+    }
+
+    /// Parses `query )` with the opening parenthesis as the current token.
+    fn parse_parenthesized_query(&mut self) -> Option<Query> {
+        match self.peek_token.kind {
+            TokenKind::Select | TokenKind::With | TokenKind::LParen => self.next_token(),
+            found => {
+                self.errors.push(ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::Select, TokenKind::With, TokenKind::LParen],
+                    found,
+                    span: self.peek_token.span,
+                });
+                return None;
+            }
+        }
+
+        let query = self.parse_query()?;
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        Some(query)
+    }
+
+    fn parse_order_by_expression(&mut self) -> Option<OrderByExpression> {
+        self.next_token();
+        let expression = self.parse_expression(Precedence::Lowest)?;
+
+        let asc = match self.peek_token.kind {
+            TokenKind::Asc => Some(true),
+            TokenKind::Desc => Some(false),
+            _ => None,
+        };
+        if asc.is_some() {
+            self.next_token();
+        }
+
+        let mut nulls_first = None;
+        if self.peek_keyword_is("NULLS") {
+            self.next_token();
+            self.next_token();
+            nulls_first = if self.current_keyword_is("FIRST") {
+                Some(true)
+            } else if self.current_keyword_is("LAST") {
+                Some(false)
+            } else {
+                self.errors.push(ParseError::ExpectedKeyword {
+                    expected: vec!["FIRST", "LAST"],
+                    found: self.current_token.kind,
+                    span: self.current_token.span,
+                });
+                return None;
+            };
+        }
+
+        let span = self.span_from(expression.span());
+        Some(OrderByExpression { expression, asc, nulls_first, span })
+    }
+
+    fn parse_from_clause(&mut self) -> Option<FromClause> {
+        let token = self.current_token.clone();
+
+        let mut tables = vec![self.parse_table_reference()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            tables.push(self.parse_table_reference()?);
+        }
+
+        let span = self.span_from(token.span);
+        Some(FromClause { token, span, tables })
+    }
+
+    /// Parses a table followed by any number of joins, which associate to the left.
+    fn parse_table_reference(&mut self) -> Option<TableReference> {
+        let mut left = self.parse_table_factor()?;
+
+        loop {
+            let kind = match self.peek_token.kind {
+                TokenKind::Join => JoinKind::Inner,
+                TokenKind::Inner => JoinKind::Inner,
+                TokenKind::Left => JoinKind::Left,
+                TokenKind::Right => JoinKind::Right,
+                TokenKind::Full => JoinKind::FullOuter,
+                TokenKind::Cross => JoinKind::Cross,
+                _ => break,
+            };
+
+            self.next_token();
+            let token = self.current_token.clone();
+
+            // OUTER is implied by LEFT, RIGHT and FULL
+            if matches!(kind, JoinKind::Left | JoinKind::Right | JoinKind::FullOuter) && self.peek_token_is(TokenKind::Outer) {
+                self.next_token();
+            }
+            if !self.current_token_is(TokenKind::Join) && !self.expect_peek(TokenKind::Join) {
+                return None;
+            }
+
+            let right = self.parse_table_factor()?;
+            let constraint = if kind == JoinKind::Cross {
+                JoinConstraint::None
+            } else {
+                self.parse_join_constraint()?
+            };
+
+            let span = self.span_from(left.span());
+            left = TableReference::Join(Join { token, span, kind, left: Box::new(left), right: Box::new(right), constraint });
+        }
+
+        Some(left)
+    }
+
+    fn parse_table_factor(&mut self) -> Option<TableReference> {
+        if self.peek_token_is(TokenKind::LParen) {
+            self.next_token();
+
+            // anything but a query inside the parentheses is a nested join such as `(b JOIN c ON ...)`
+            if !self.parenthesized_query_follows() {
+                let nested = self.parse_table_reference()?;
+                if !self.expect_peek(TokenKind::RParen) {
+                    return None;
+                }
+                return Some(nested);
+            }
+
+            let start = self.current_token.span;
+            let subquery = Box::new(self.parse_parenthesized_query()?);
+            let alias = self.parse_alias()?;
+            let span = self.span_from(start);
+            return Some(TableReference::Derived { subquery, alias, span });
+        }
+
+        let name = self.parse_table_name()?;
+        let alias = self.parse_alias()?;
+        let span = self.span_from(name.span());
+        Some(TableReference::Table { name, alias, span })
+    }
+
+    fn parse_join_constraint(&mut self) -> Option<JoinConstraint> {
+        match self.peek_token.kind {
+            TokenKind::On => {
+                self.next_token();
+                self.next_token();
+                Some(JoinConstraint::On(self.parse_expression(Precedence::Lowest)?))
+            }
+            TokenKind::Using => {
+                self.next_token();
+                if !self.expect_peek(TokenKind::LParen) {
+                    return None;
+                }
+                let columns = self.parse_expression_list()?;
+                if !self.expect_peek(TokenKind::RParen) {
+                    return None;
+                }
+                Some(JoinConstraint::Using(columns))
+            }
+            found => {
+                self.errors.push(ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::On, TokenKind::Using],
+                    found,
+                    span: self.peek_token.span,
+                });
+                None
+            }
+        }
+    }
+
+    fn parse_select_item(&mut self) -> Option<SelectItem> {
+        self.next_token();
+
+        if self.current_token_is(TokenKind::Asterisk) {
+            return Some(SelectItem::Wildcard(self.current_token.span));
+        }
+
+        // a dotted name may end in `.*`, which is only allowed here
+        let expression = if self.current_token_is(TokenKind::Identifier) && self.peek_token_is(TokenKind::Dot) {
+            let (name, wildcard) = self.parse_qualified_name(true)?;
+            if wildcard {
+                let span = self.span_from(name.span());
+                return Some(SelectItem::QualifiedWildcard { table: name, span });
+            }
+            self.parse_infix_expressions(name, Precedence::Lowest)?
+        } else {
+            self.parse_expression(Precedence::Lowest)?
+        };
+
+        let alias = self.parse_alias()?;
+        Some(SelectItem::Expression { expression, alias })
+    }
+
+    /// Parses an optional alias, either `AS name` or just `name`. Like `parse_where_clause` the outer
+    /// `Option` signals a parse error.
+    fn parse_alias(&mut self) -> Option<Option<Expression>> {
+        if self.peek_token_is(TokenKind::As) {
+            self.next_token();
+        } else if !self.peek_token_is(TokenKind::Identifier) {
+            return Some(None);
+        }
+
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        Some(Some(self.parse_identifier()?))
+    }
+
+    /// Parses an optional `WHERE <expression>` following the current token. The outer `Option` signals
+    /// a parse error, the inner one whether a predicate was present at all.
+    fn parse_where_clause(&mut self) -> Option<Option<Expression>> {
+        if !self.peek_token_is(TokenKind::Where) {
+            return Some(None);
+        }
+
+        // move past WHERE to the start of the predicate
+        self.next_token();
+        self.next_token();
+
+        Some(Some(self.parse_expression(Precedence::Lowest)?))
+    }
+
+    fn parse_insert_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenKind::Into) {
+            return None;
+        }
+
+        let table_name = self.parse_table_name()?;
+
+        // the column list is optional, values are then matched against the table's own column order
+        let mut columns = vec![];
+        if self.peek_token_is(TokenKind::LParen) {
+            self.next_token();
+            columns = self.parse_expression_list()?;
+            if !self.expect_peek(TokenKind::RParen) {
+                return None;
+            }
+        }
+
+        if !self.expect_peek(TokenKind::Values) {
+            return None;
+        }
+
+        let mut rows = vec![self.parse_values_row()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            rows.push(self.parse_values_row()?);
+        }
+
+        // every row has to line up with the column list, or with the first row when no columns were named
+        let width = if columns.is_empty() { rows[0].len() } else { columns.len() };
+        if let Some(row) = rows.iter().find(|row| row.len() != width) {
+            let span = row[0].span().to(row[row.len() - 1].span());
+            self.errors.push(ParseError::RowWidthMismatch { expected: width, found: row.len(), span });
+            return None;
+        }
+
+        let span = self.span_from(token.span);
+        Some(Statement::Insert(InsertStatement { token, span, table_name, columns, rows }))
+    }
+
+    fn parse_values_row(&mut self) -> Option<Vec<Expression>> {
+        if !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        let mut values = vec![];
+        loop {
+            self.next_token();
+            values.push(self.parse_expression(Precedence::Lowest)?);
+
+            if !self.peek_token_is(TokenKind::Comma) {
+                break;
+            }
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        Some(values)
+    }
+
+    fn parse_update_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        let table_name = self.parse_table_name()?;
+        if !self.expect_peek(TokenKind::Set) {
+            return None;
+        }
+
+        let mut assignments = vec![self.parse_assignment()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            assignments.push(self.parse_assignment()?);
+        }
+
+        let where_clause = self.parse_where_clause()?;
+        let span = self.span_from(token.span);
+        Some(Statement::Update(UpdateStatement { token, span, table_name, assignments, where_clause }))
+    }
+
+    fn parse_assignment(&mut self) -> Option<Assignment> {
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        let column = self.parse_identifier()?;
+        if !self.expect_peek(TokenKind::Equals) {
+            return None;
+        }
+
+        self.next_token();
+        let value = self.parse_expression(Precedence::Lowest)?;
+        let span = column.span().to(value.span());
+        Some(Assignment { column, value, span })
+    }
+
+    fn parse_delete_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenKind::From) {
+            return None;
+        }
+
+        let table_name = self.parse_table_name()?;
+        let where_clause = self.parse_where_clause()?;
+        let span = self.span_from(token.span);
+        Some(Statement::Delete(DeleteStatement { token, span, table_name, where_clause }))
+    }
+
+    fn parse_create_table_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenKind::Table) {
+            return None;
+        }
+
+        let mut if_not_exists = false;
+        if self.peek_token_is(TokenKind::If) {
+            self.next_token();
+            if !self.expect_peek(TokenKind::Not) || !self.expect_peek(TokenKind::Exists) {
+                return None;
+            }
+            if_not_exists = true;
+        }
+
+        let table_name = self.parse_table_name()?;
+        if !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        let mut columns = vec![self.parse_column_definition()?];
+        while self.peek_token_is(TokenKind::Comma) {
+            self.next_token();
+            columns.push(self.parse_column_definition()?);
+        }
+
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        let span = self.span_from(token.span);
+        Some(Statement::CreateTable(CreateTableStatement { token, span, if_not_exists, table_name, columns }))
+    }
+
+    fn parse_drop_table_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenKind::Table) {
+            return None;
+        }
+
+        let mut if_exists = false;
+        if self.peek_token_is(TokenKind::If) {
+            self.next_token();
+            if !self.expect_peek(TokenKind::Exists) {
+                return None;
+            }
+            if_exists = true;
+        }
+
+        let table_name = self.parse_table_name()?;
+        let span = self.span_from(token.span);
+        Some(Statement::DropTable(DropTableStatement { token, span, if_exists, table_name }))
+    }
+
+    fn parse_alter_table_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenKind::Table) {
+            return None;
+        }
+
+        let table_name = self.parse_table_name()?;
+        let action = self.parse_alter_table_action()?;
+        let span = self.span_from(token.span);
+        Some(Statement::AlterTable(AlterTableStatement { token, span, table_name, action }))
+    }
+
+    fn parse_alter_table_action(&mut self) -> Option<AlterTableAction> {
+        self.next_token();
+
+        match self.current_token.kind {
+            TokenKind::Identifier if self.current_keyword_is("ADD") => {
+                // COLUMN is optional after ADD and DROP
+                if self.peek_keyword_is("COLUMN") {
+                    self.next_token();
+                }
+                Some(AlterTableAction::AddColumn(self.parse_column_definition()?))
+            }
+            TokenKind::Drop => {
+                if self.peek_keyword_is("COLUMN") {
+                    self.next_token();
+                }
+                if !self.expect_peek(TokenKind::Identifier) {
+                    return None;
+                }
+                Some(AlterTableAction::DropColumn(self.parse_identifier()?))
+            }
+            TokenKind::Identifier if self.current_keyword_is("RENAME") && self.peek_keyword_is("COLUMN") => {
+                self.next_token();
+                if !self.expect_peek(TokenKind::Identifier) {
+                    return None;
+                }
+                let from = self.parse_identifier()?;
+                if !self.expect_peek_keyword("TO") || !self.expect_peek(TokenKind::Identifier) {
+                    return None;
+                }
+                let to = self.parse_identifier()?;
+                Some(AlterTableAction::RenameColumn { from, to })
+            }
+            TokenKind::Identifier if self.current_keyword_is("RENAME") => {
+                if !self.expect_peek_keyword("TO") {
+                    return None;
+                }
+                Some(AlterTableAction::RenameTable(self.parse_table_name()?))
+            }
+            kind => {
+                self.errors.push(ParseError::ExpectedKeyword {
+                    expected: vec!["ADD", "DROP", "RENAME"],
+                    found: kind,
+                    span: self.current_token.span,
+                });
+                None
+            }
+        }
+    }
+
+    fn parse_column_definition(&mut self) -> Option<ColumnDefinition> {
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_data_type()?;
+        let (mut not_null, mut primary_key, mut default) = (false, false, None);
+
+        // constraints may follow in any order
+        loop {
+            match self.peek_token.kind {
+                TokenKind::Not => {
+                    self.next_token();
+                    if !self.expect_peek(TokenKind::Null) {
+                        return None;
+                    }
+                    not_null = true;
+                }
+                TokenKind::Primary => {
+                    self.next_token();
+                    if !self.expect_peek_keyword("KEY") {
+                        return None;
+                    }
+                    primary_key = true;
+                }
+                TokenKind::Default => {
+                    self.next_token();
+                    self.next_token();
+                    // stop short of NOT so that a following NOT NULL is read as a constraint
+                    default = Some(self.parse_expression(Precedence::Predicate)?);
+                }
+                _ => break,
+            }
+        }
+
+        let span = self.span_from(name.span());
+        Some(ColumnDefinition { name, data_type, not_null, primary_key, default, span })
+    }
+
+    /// Parses a type name such as `INT` or `VARCHAR(20)`. Type names are not keywords, so they arrive
+    /// as identifiers and are only recognised here.
+    fn parse_data_type(&mut self) -> Option<DataType> {
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        let mut data_type = match DataType::from_name(&self.current_token.literal) {
+            Some(data_type) => data_type,
+            None => {
+                let (name, span) = (self.current_token.literal.clone(), self.current_token.span);
+                self.errors.push(ParseError::UnknownDataType { name, span });
+                return None;
+            }
+        };
+
+        match &mut data_type {
+            DataType::Varchar(length) if self.peek_token_is(TokenKind::LParen) => {
+                self.next_token();
+                *length = Some(self.parse_type_parameter()?);
+                if !self.expect_peek(TokenKind::RParen) {
+                    return None;
+                }
+            }
+            DataType::Decimal { precision, scale } if self.peek_token_is(TokenKind::LParen) => {
+                self.next_token();
+                *precision = Some(self.parse_type_parameter()?);
+                if self.peek_token_is(TokenKind::Comma) {
+                    self.next_token();
+                    *scale = Some(self.parse_type_parameter()?);
+                }
+                if !self.expect_peek(TokenKind::RParen) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+
+        Some(data_type)
+    }
+
+    fn parse_type_parameter(&mut self) -> Option<u32> {
+        if !self.expect_peek(TokenKind::Int) {
+            return None;
+        }
+
+        match self.current_token.literal.parse() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                let (literal, span) = (self.current_token.literal.clone(), self.current_token.span);
+                self.errors.push(ParseError::InvalidTypeParameter { literal, span });
+                None
+            }
+        }
+    }
+
+    fn parse_expression_list(&mut self) -> Option<Vec<Expression>> {
+        let mut expressions = vec![];
+
+        // At least one identifier is required - move to it
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        let identifier = self.parse_identifier()?;
+        expressions.push(identifier);
+
+        // keep collecting expressions until we run out of them
+        while self.peek_token_is(TokenKind::Comma) {
+            // make comma the current token
+            self.next_token();
+
+            // an identifier should follow
+            if !self.expect_peek(TokenKind::Identifier) {
+                return None;
+            }
+
+            expressions.push(self.parse_identifier()?);
+        }
+
+        Some(expressions)
+    }
+
+    fn parse_table_name(&mut self) -> Option<Expression> {
+        if !self.expect_peek(TokenKind::Identifier) {
+            return None;
+        }
+
+        self.parse_qualified_identifier()
+    }
+
+    /// Parses a single, undotted name such as a column in a definition or an alias.
+    fn parse_identifier(&mut self) -> Option<Expression> {
+        Some(Expression::Identifier(self.current_identifier()))
+    }
+
+    fn parse_qualified_identifier(&mut self) -> Option<Expression> {
+        let (name, _) = self.parse_qualified_name(false)?;
+        Some(name)
+    }
+
+    /// Parses a name in an expression, or a typed literal such as `DECIMAL '19.99'` when the type
+    /// name is followed by a string.
+    fn parse_name_or_typed_literal(&mut self) -> Option<Expression> {
+        let typed = self.peek_token_is(TokenKind::String)
+            && matches!(DataType::from_name(&self.current_token.literal), Some(DataType::Decimal { .. }));
+
+        if typed {
+            return self.parse_decimal_literal();
+        }
+
+        self.parse_qualified_identifier()
+    }
+
+    /// Parses `name` or a dotted `schema.table.column` starting at the current identifier. With
+    /// `allow_wildcard` the name may end in `.*`, which is reported through the returned flag.
+    fn parse_qualified_name(&mut self, allow_wildcard: bool) -> Option<(Expression, bool)> {
+        let token = self.current_token.clone();
+        let mut parts = vec![self.current_identifier()];
+        let mut wildcard = false;
+
+        while self.peek_token_is(TokenKind::Dot) {
+            self.next_token();
+
+            if allow_wildcard && self.peek_token_is(TokenKind::Asterisk) {
+                self.next_token();
+                wildcard = true;
+                break;
+            }
+
+            if !self.peek_token_is(TokenKind::Identifier) {
+                let mut expected = vec![TokenKind::Identifier];
+                if allow_wildcard {
+                    expected.push(TokenKind::Asterisk);
+                }
+                self.errors.push(ParseError::UnexpectedToken { expected, found: self.peek_token.kind, span: self.peek_token.span });
+                return None;
+            }
+
+            self.next_token();
+            parts.push(self.current_identifier());
+        }
+
+        let name = if parts.len() == 1 {
+            Expression::Identifier(parts.remove(0))
+        } else {
+            let span = parts[0].span.to(parts[parts.len() - 1].span);
+            Expression::CompoundIdentifier(CompoundIdentifier { token, span, parts })
+        };
+
+        Some((name, wildcard))
+    }
+
+    /// Builds the identifier at the current token. Unquoted names are folded to lower case so `Foo`
+    /// and `foo` name the same thing, while quoted names are kept exactly as written.
+    fn current_identifier(&self) -> Identifier {
+        let literal = &self.current_token.literal;
+        let quoted = literal.starts_with('"');
+        let value = if quoted {
+            literal[1..literal.len() - 1].replace("\"\"", "\"")
+        } else {
+            literal.to_lowercase()
+        };
+
+        Identifier { token: self.current_token.clone(), span: self.current_token.span, value, quoted }
+    }
+
+    fn parse_integer_literal(&mut self) -> Option<Expression> {
+        let value = match self.current_token.literal.parse() {
+            Ok(value) => value,
+            Err(_) => {
+                self.push_out_of_range_error();
+                return None;
+            }
+        };
+
+        let literal = IntLiteral { token: self.current_token.clone(), span: self.current_token.span, value };
+        Some(Expression::Int(literal))
+    }
+
+    /// Parses the integer after a `-` that starts at `start` as a single negative literal.
+    fn parse_negative_integer_literal(&mut self, start: Span) -> Option<Expression> {
+        let span = self.span_from(start);
+        let token = Token { kind: TokenKind::Int, literal: format!("-{}", self.current_token.literal), span };
+
+        let value = match token.literal.parse() {
+            Ok(value) => value,
+            Err(_) => {
+                self.errors.push(ParseError::NumberOutOfRange { kind: token.kind, literal: token.literal, span });
+                return None;
+            }
+        };
+
+        Some(Expression::Int(IntLiteral { token, span, value }))
+    }
+
+    fn parse_float_literal(&mut self) -> Option<Expression> {
+        let value: f64 = match self.current_token.literal.parse() {
+            Ok(value) if f64::is_finite(value) => value,
+            _ => {
+                self.push_out_of_range_error();
+                return None;
+            }
+        };
+
+        let literal = FloatLiteral { token: self.current_token.clone(), span: self.current_token.span, value };
+        Some(Expression::Float(literal))
+    }
+
+    /// Parses `DECIMAL '-12.50'` from the type name; the string holds an optional sign, digits and an
+    /// optional fraction.
+    fn parse_decimal_literal(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+        self.next_token();
+
+        let literal = self.current_token.literal.clone();
+        let (sign, unsigned) = match literal.strip_prefix(['+', '-']) {
+            Some(rest) => (&literal[..1], rest),
+            None => ("", literal.as_str()),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let digits_only = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        let value = if digits_only(whole) && digits_only(fraction) && whole.len() + fraction.len() > 0 {
+            format!("{}{}{}", sign, whole, fraction).parse().ok()
+        } else {
+            None
+        };
+
+        let value = match value {
+            Some(value) => value,
+            None => {
+                self.errors.push(ParseError::InvalidDecimal { literal, span: self.current_token.span });
+                return None;
+            }
+        };
+
+        let span = self.span_from(token.span);
+        let literal = DecimalLiteral {
+            token,
+            span,
+            value,
+            scale: fraction.len() as u32,
+        };
+        Some(Expression::Decimal(literal))
+    }
+
+    fn parse_boolean_literal(&mut self) -> Option<Expression> {
+        let literal = BooleanLiteral {
+            token: self.current_token.clone(),
+            span: self.current_token.span,
+            value: self.current_token_is(TokenKind::True),
+        };
+        Some(Expression::Boolean(literal))
+    }
+
+    fn parse_null_literal(&mut self) -> Option<Expression> {
+        let literal = NullLiteral { token: self.current_token.clone(), span: self.current_token.span };
+        Some(Expression::Null(literal))
+    }
+
+    fn parse_string_literal(&mut self) -> Option<Expression> {
+        let literal = StringLiteral {
+            token: self.current_token.clone(),
+            span: self.current_token.span,
+            value: self.current_token.literal.clone(),
+        };
+        Some(Expression::String(literal))
+    }
+
+    fn parse_prefix_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+        let operator = self.unary_operators[&token.kind];
+
+        // NOT, however it is spelled, applies to a whole comparison; minus only to the operand next to it
+        let precedence = if operator == UnaryOperator::Not { Precedence::Not } else { Precedence::Prefix };
+
+        self.next_token();
+
+        // fold a negated integer into one literal so its range is checked with the sign, which
+        // i64::MIN needs, unless a cast or call binds the integer first
+        if token.kind == TokenKind::Minus && self.current_token_is(TokenKind::Int) && self.peek_precedence() <= Precedence::Prefix {
+            return self.parse_negative_integer_literal(token.span);
+        }
+
+        let right = Box::new(self.parse_expression(precedence)?);
+        let span = token.span.to(right.span());
+        let prefix_expression = Expression::Prefixed(PrefixExpression { token, span, operator, right });
+        Some(prefix_expression)
+    }
+
+    fn parse_infix_expression(&mut self, left: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+        let operator = self.binary_operators[&token.kind];
+        let precedence = self.current_precedence();
+
+        self.next_token();
+
+        let right = Box::new(self.parse_expression(precedence)?);
+        let span = left.span().to(right.span());
+        let infix_expression = Expression::Infixed(InfixExpression { token, span, left: Box::new(left), operator, right });
+        Some(infix_expression)
+    }
+
+    fn parse_predicate(&mut self, left: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+        self.parse_predicate_rest(token, left, false)
+    }
+
+    /// Infix NOT, which must be followed by the predicate it negates.
+    fn parse_negated_predicate(&mut self, left: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+        match self.peek_token.kind {
+            TokenKind::In | TokenKind::Between | TokenKind::Like => {
+                self.next_token();
+                self.parse_predicate_rest(token, left, true)
+            }
+            found => {
+                self.errors.push(ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::In, TokenKind::Between, TokenKind::Like],
+                    found,
+                    span: self.peek_token.span,
+                });
+                None
+            }
+        }
+    }
+
+    /// Parses the right hand side of IN, BETWEEN or LIKE, with the keyword as the current token.
+    fn parse_predicate_rest(&mut self, token: Token, expression: Expression, negated: bool) -> Option<Expression> {
+        match self.current_token.kind {
+            TokenKind::In => self.parse_in_predicate(token, expression, negated),
+            TokenKind::Between => self.parse_between_predicate(token, expression, negated),
+            _ => self.parse_like_predicate(token, expression, negated),
+        }
+    }
+
+    fn parse_in_predicate(&mut self, token: Token, expression: Expression, negated: bool) -> Option<Expression> {
+        if !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        if self.parenthesized_query_follows() {
+            let subquery = Box::new(self.parse_parenthesized_query()?);
+            let span = self.span_from(expression.span());
+            let expression = Box::new(expression);
+            return Some(Expression::InSubquery(InSubquery { token, span, expression, negated, subquery }));
+        }
+
+        let mut list = vec![];
+        loop {
+            self.next_token();
+            list.push(self.parse_expression(Precedence::Lowest)?);
+            if !self.peek_token_is(TokenKind::Comma) {
+                break;
+            }
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        let span = self.span_from(expression.span());
+        Some(Expression::InList(InList { token, span, expression: Box::new(expression), negated, list }))
+    }
+
+    fn parse_between_predicate(&mut self, token: Token, expression: Expression, negated: bool) -> Option<Expression> {
+        // the bounds stop short of AND, so the one separating them is not read as a conjunction
+        self.next_token();
+        let low = Box::new(self.parse_expression(Precedence::Predicate)?);
+
+        if !self.expect_peek(TokenKind::And) {
+            return None;
+        }
+
+        self.next_token();
+        let high = Box::new(self.parse_expression(Precedence::Predicate)?);
+
+        let span = self.span_from(expression.span());
+        Some(Expression::Between(BetweenExpression { token, span, expression: Box::new(expression), negated, low, high }))
+    }
+
+    fn parse_like_predicate(&mut self, token: Token, expression: Expression, negated: bool) -> Option<Expression> {
+        self.next_token();
+        let pattern = Box::new(self.parse_expression(Precedence::Predicate)?);
+
+        let mut escape = None;
+        if self.peek_keyword_is("ESCAPE") {
+            self.next_token();
+            self.next_token();
+            escape = Some(Box::new(self.parse_expression(Precedence::Predicate)?));
+        }
+
+        let span = self.span_from(expression.span());
+        Some(Expression::Like(LikeExpression { token, span, expression: Box::new(expression), negated, pattern, escape }))
+    }
+
+    fn parse_is_null_expression(&mut self, expression: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        let negated = self.peek_token_is(TokenKind::Not);
+        if negated {
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenKind::Null) {
+            return None;
+        }
+
+        let span = self.span_from(expression.span());
+        Some(Expression::IsNull(IsNullExpression { token, span, expression: Box::new(expression), negated }))
+    }
+
+    fn parse_cast_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        self.next_token();
+        let expression = Box::new(self.parse_expression(Precedence::Lowest)?);
+
+        if !self.expect_peek(TokenKind::As) {
+            return None;
+        }
+
+        let data_type = self.parse_data_type()?;
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        let span = self.span_from(token.span);
+        Some(Expression::Cast(CastExpression { token, span, expression, data_type }))
+    }
+
+    /// The `expression::type` shorthand for CAST.
+    fn parse_type_annotation(&mut self, expression: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+        let data_type = self.parse_data_type()?;
+        let span = self.span_from(expression.span());
+        Some(Expression::Cast(CastExpression { token, span, expression: Box::new(expression), data_type }))
+    }
+
+    fn parse_case_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        let mut operand = None;
+        if !self.peek_token_is(TokenKind::When) {
+            self.next_token();
+            operand = Some(Box::new(self.parse_expression(Precedence::Lowest)?));
+        }
+
+        // at least one WHEN is required
+        if !self.expect_peek(TokenKind::When) {
+            return None;
+        }
+
+        let mut when_clauses = vec![];
+        loop {
+            let start = self.current_token.span;
+            self.next_token();
+            let condition = self.parse_expression(Precedence::Lowest)?;
+
+            if !self.expect_peek(TokenKind::Then) {
+                return None;
+            }
+
+            self.next_token();
+            let result = self.parse_expression(Precedence::Lowest)?;
+            let span = self.span_from(start);
+            when_clauses.push(WhenClause { condition, result, span });
+
+            if !self.peek_token_is(TokenKind::When) {
+                break;
+            }
+            self.next_token();
+        }
+
+        let mut else_result = None;
+        if self.peek_token_is(TokenKind::Else) {
+            self.next_token();
+            self.next_token();
+            else_result = Some(Box::new(self.parse_expression(Precedence::Lowest)?));
+        }
+
+        if !self.expect_peek_keyword("END") {
+            return None;
+        }
+
+        let span = self.span_from(token.span);
+        Some(Expression::Case(CaseExpression { token, span, operand, when_clauses, else_result }))
+    }
+
+    fn parse_exists_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenKind::LParen) {
+            return None;
+        }
+
+        let subquery = Box::new(self.parse_parenthesized_query()?);
+        let span = self.span_from(token.span);
+        Some(Expression::Exists(ExistsExpression { token, span, subquery }))
+    }
+
+    fn parse_call_expression(&mut self, function: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        if !matches!(function, Expression::Identifier(_) | Expression::CompoundIdentifier(_)) {
+            self.errors.push(ParseError::NotCallable { span: function.span() });
+            return None;
+        }
+
+        let mut distinct = false;
+        let mut arguments = vec![];
+
+        if self.peek_token_is(TokenKind::Asterisk) {
+            self.next_token();
+            arguments.push(FunctionArgument::Wildcard(self.current_token.span));
+        } else if !self.peek_token_is(TokenKind::RParen) {
+            if self.peek_token_is(TokenKind::Distinct) {
+                self.next_token();
+                distinct = true;
+            }
+            loop {
+                self.next_token();
+                arguments.push(FunctionArgument::Expression(self.parse_expression(Precedence::Lowest)?));
+                if !self.peek_token_is(TokenKind::Comma) {
+                    break;
+                }
+                self.next_token();
+            }
+        }
+
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        let span = self.span_from(function.span());
+        Some(Expression::Call(CallExpression { token, span, function: Box::new(function), distinct, arguments }))
+    }
+
+    fn parse_grouped_expression(&mut self) -> Option<Expression> {
+        if self.parenthesized_query_follows() {
+            let token = self.current_token.clone();
+            let query = Box::new(self.parse_parenthesized_query()?);
+            let span = self.span_from(token.span);
+            return Some(Expression::Subquery(Subquery { token, span, query }));
+        }
+
+        self.next_token();
+
+        let expression = self.parse_expression(Precedence::Lowest)?;
+        if !self.expect_peek(TokenKind::RParen) {
+            return None;
+        }
+
+        Some(expression)
+    }
+}
+
+// Initializers
+impl Parser {
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    pub fn new(mut lexer: Lexer) -> Self {
+        let current_token = lexer.next_token();
+        let peek_token = lexer.next_token();
+        let errors = lexer.take_errors();
+        let mut p = Parser {
+            lexer,
+            current_token,
+            peek_token,
+            errors,
+            prefix_parsers: Default::default(),
+            infix_parsers: Default::default(),
+            unary_operators: Default::default(),
+            binary_operators: Default::default(),
+        };
+
+        p.register_prefix(TokenKind::Identifier, Parser::parse_name_or_typed_literal);
+        p.register_prefix(TokenKind::Int, Parser::parse_integer_literal);
+        p.register_prefix(TokenKind::Float, Parser::parse_float_literal);
+        p.register_prefix(TokenKind::String, Parser::parse_string_literal);
+        p.register_unary_operator(TokenKind::Bang);
+        p.register_unary_operator(TokenKind::Minus);
+        p.register_unary_operator(TokenKind::Not);
+        p.register_prefix(TokenKind::LParen, Parser::parse_grouped_expression);
+        p.register_prefix(TokenKind::Exists, Parser::parse_exists_expression);
+        p.register_prefix(TokenKind::True, Parser::parse_boolean_literal);
+        p.register_prefix(TokenKind::False, Parser::parse_boolean_literal);
+        p.register_prefix(TokenKind::Null, Parser::parse_null_literal);
+        p.register_prefix(TokenKind::Case, Parser::parse_case_expression);
+        p.register_prefix(TokenKind::Cast, Parser::parse_cast_expression);
+
+        for kind in [
+            TokenKind::Plus,
+            TokenKind::Minus,
+            TokenKind::Asterisk,
+            TokenKind::Slash,
+            TokenKind::Equals,
+            TokenKind::NotEq,
+            TokenKind::Lt,
+            TokenKind::Gt,
+            TokenKind::LtEq,
+            TokenKind::GtEq,
+            TokenKind::And,
+            TokenKind::Or,
+        ] {
+            p.register_binary_operator(kind);
+        }
+        p.register_infix(TokenKind::LParen, Parser::parse_call_expression);
+        p.register_infix(TokenKind::In, Parser::parse_predicate);
+        p.register_infix(TokenKind::Between, Parser::parse_predicate);
+        p.register_infix(TokenKind::Like, Parser::parse_predicate);
+        p.register_infix(TokenKind::Is, Parser::parse_is_null_expression);
+        p.register_infix(TokenKind::DoubleColon, Parser::parse_type_annotation);
+        p.register_infix(TokenKind::Not, Parser::parse_negated_predicate);
+        p
+    }
+
+    fn register_prefix(&mut self, kind: TokenKind, prefix_parser: PrefixParser) {
+        self.prefix_parsers.insert(kind, prefix_parser);
+    }
+
+    fn register_infix(&mut self, kind: TokenKind, infix_parser: InfixParser) {
+        self.infix_parsers.insert(kind, infix_parser);
+    }
+
+    fn register_unary_operator(&mut self, kind: TokenKind) {
+        let operator = UnaryOperator::from_token_kind(kind).expect("prefix operator tokens map to a UnaryOperator");
+        self.unary_operators.insert(kind, operator);
+        self.register_prefix(kind, Parser::parse_prefix_expression);
+    }
+
+    fn register_binary_operator(&mut self, kind: TokenKind) {
+        let operator = BinaryOperator::from_token_kind(kind).expect("infix operator tokens map to a BinaryOperator");
+        self.binary_operators.insert(kind, operator);
+        self.register_infix(kind, Parser::parse_infix_expression);
+    }
+}
+
+// Token Helpers
+impl Parser {
+    fn next_token(&mut self) {
+        self.current_token = self.peek_token.clone();
+        self.peek_token = self.lexer.next_token();
+        self.errors.append(&mut self.lexer.take_errors());
+    }
+
+    /// Skips ahead to the semicolon ending the current statement, or to the end of input.
+    fn synchronize(&mut self) {
+        while !self.current_token_is(TokenKind::Semicolon) && !self.current_token_is(TokenKind::Eof) {
+            self.next_token();
+        }
+    }
+
+    /// Whether the parenthesis at the current token holds a query. Its first operand may sit in further
+    /// parentheses, as in `((SELECT a FROM t) UNION (SELECT b FROM u))`, and only the token after such an
+    /// operand tells a query from an expression such as `((SELECT a FROM t) + 1)`, so this reads ahead
+    /// on a copy of the lexer.
+    fn parenthesized_query_follows(&self) -> bool {
+        let mut lexer = self.lexer.clone();
+        let mut kind = self.peek_token.kind;
+
+        // parentheses open around the first SELECT, the current one included
+        let mut depth = 1;
+        while kind == TokenKind::LParen {
+            depth += 1;
+            kind = lexer.next_token().kind;
+        }
+        if !matches!(kind, TokenKind::Select | TokenKind::With) {
+            return false;
+        }
+
+        // step out one query at a time until the current parenthesis closes
+        while depth > 1 {
+            let query_depth = depth;
+            while depth >= query_depth {
+                match lexer.next_token().kind {
+                    TokenKind::LParen => depth += 1,
+                    TokenKind::RParen => depth -= 1,
+                    TokenKind::Eof => return false,
+                    _ => {}
+                }
+            }
+
+            kind = lexer.next_token().kind;
+            while kind == TokenKind::RParen && depth > 0 {
+                depth -= 1;
+                kind = lexer.next_token().kind;
+            }
+
+            let continues = matches!(
+                kind,
+                TokenKind::Union | TokenKind::Intersect | TokenKind::Except | TokenKind::Order | TokenKind::Limit | TokenKind::Offset
+            );
+            if depth > 0 && !continues {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn current_token_is(&self, kind: TokenKind) -> bool {
+        self.current_token.kind == kind
+    }
+
+    fn peek_token_is(&self, kind: TokenKind) -> bool {
+        self.peek_token.kind == kind
+    }
+
+    /// Words that are only keywords in one position are lexed as identifiers, so they stay usable as
+    /// names everywhere else, and are recognised by their literal where they matter.
+    fn current_keyword_is(&self, keyword: &str) -> bool {
+        self.current_token_is(TokenKind::Identifier) && self.current_token.literal.eq_ignore_ascii_case(keyword)
+    }
+
+    fn peek_keyword_is(&self, keyword: &str) -> bool {
+        self.peek_token_is(TokenKind::Identifier) && self.peek_token.literal.eq_ignore_ascii_case(keyword)
+    }
+
+    fn peek_precedence(&self) -> Precedence {
+        precedence_of(self.peek_token.kind)
+    }
+
+    fn current_precedence(&self) -> Precedence {
+        precedence_of(self.current_token.kind)
+    }
+
+    fn push_out_of_range_error(&mut self) {
+        self.errors.push(ParseError::NumberOutOfRange {
+            kind: self.current_token.kind,
+            literal: self.current_token.literal.clone(),
+            span: self.current_token.span,
+        });
+    }
+
+    /// The span from `start` through the current token, which is the last one of the node being built.
+    fn span_from(&self, start: Span) -> Span {
+        start.to(self.current_token.span)
+    }
+
+    fn expect_peek(&mut self, kind: TokenKind) -> bool {
+        if self.peek_token_is(kind) {
+            self.next_token();
+            true
+        } else {
+            self.errors.push(ParseError::UnexpectedToken {
+                expected: vec![kind],
+                found: self.peek_token.kind,
+                span: self.peek_token.span,
+            });
+            false
+        }
+    }
+
+    fn expect_peek_keyword(&mut self, keyword: &'static str) -> bool {
+        if self.peek_keyword_is(keyword) {
+            self.next_token();
+            true
+        } else {
+            self.errors.push(ParseError::ExpectedKeyword {
+                expected: vec![keyword],
+                found: self.peek_token.kind,
+                span: self.peek_token.span,
+            });
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Statement {
+        let mut parser = Parser::new(Lexer::new(s));
+        let statement = parser.parse_statement();
+        assert!(parser.errors.is_empty());
+        statement.unwrap()
+    }
+
+    /// Parses a statement that should fail and returns the problems found.
+    fn parse_errors(input: &str) -> Vec<ParseError> {
+        let mut p = Parser::new(Lexer::new(input));
+        assert!(p.parse_statement().is_none(), "{} should not parse", input);
+        p.errors
+    }
+
+    /// A span on the first line of an ASCII input, where the column follows from the offset.
+    fn at(start: usize, end: usize) -> Span {
+        Span { start, end, line: 1, column: start + 1 }
+    }
+
+    fn assert_identifier(s: &str, expression: &Expression) {
+        match expression {
+            Expression::Identifier(Identifier { value, .. }) => assert_eq!(value, s),
+            _ => panic!("{} is not an identifier", expression)
+        }
+    }
+
+    fn assert_int_literal(value: i64, expression: &Expression) {
+        if let Expression::Int(i) = expression {
+            assert_eq!(i.value, value);
+        } else {
+            panic!("{} is not an int literal", expression)
+        }
+    }
+
+    fn assert_token(token: &Token, kind: TokenKind, literal: &str) {
+        assert_eq!(token.kind, kind);
+        assert_eq!(token.literal, literal);
+    }
+
+    fn extract_query(statement: &Statement) -> &Query {
+        match statement {
+            Statement::Query(query) => query,
+            _ => panic!("{} is not a query", statement),
+        }
+    }
+
+    fn extract_query_select(query: &Query) -> &SelectStatement {
+        match &query.body {
+            SetExpression::Select(select) => select,
+            body => panic!("{} is not a plain select", body),
+        }
+    }
+
+    fn extract_select(statement: &Statement) -> &SelectStatement {
+        extract_query_select(extract_query(statement))
+    }
+
+    fn extract_table_name(s: &SelectStatement) -> &Expression {
+        match &s.from.tables[0] {
+            TableReference::Table { name, .. } => name,
+            table => panic!("{} is not a table name", table),
+        }
+    }
+
+    fn assert_select_statement(statement: &Statement, table_name: &str, attributes: &[&str]) {
+        let s = extract_select(statement);
+        assert_token(&s.token, TokenKind::Select, "SELECT");
+        assert_identifier(table_name, extract_table_name(s));
+        assert_eq!(attributes.len(), s.projection.len());
+        for (expect, got) in attributes.iter().zip(s.projection.iter()) {
+            match got {
+                SelectItem::Expression { expression, .. } => assert_identifier(expect, expression),
+                _ => panic!("{} is not an expression", got),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_select_statement() {
+        struct Test<'a> {
+            input: &'a str,
+            table_name: &'a str,
+            expressions: &'a [&'a str],
+        }
+
+        for t in &[
+            Test { input: "select name, age, gender from employee", table_name: "employee", expressions: &["name", "age", "gender"] },
+            Test { input: "select gender from foobar", table_name: "foobar", expressions: &["gender"] },
+        ] {
+            let statement = parse(t.input);
+            println!("{}", statement);
+            assert_select_statement(&statement, t.table_name, t.expressions);
+        }
+    }
+
+    #[test]
+    fn parse_bad_select_statement() {
+        for (input, expected, found, span) in [
+            ("select foo from", TokenKind::Identifier, TokenKind::Eof, Span { start: 15, end: 15, line: 1, column: 16 }),
+            ("select blah", TokenKind::From, TokenKind::Eof, Span { start: 11, end: 11, line: 1, column: 12 }),
+        ] {
+            assert_eq!(parse_errors(input), vec![ParseError::UnexpectedToken { expected: vec![expected], found, span }]);
+        }
+
+        for (input, expected_error) in [
+            ("select from bar", ParseError::ExpectedExpression { found: TokenKind::From, span: at(7, 11) }),
+            ("select a, from bar", ParseError::ExpectedExpression { found: TokenKind::From, span: at(10, 14) }),
+            ("select t. from bar", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier, TokenKind::Asterisk],
+                found: TokenKind::From,
+                span: at(10, 14),
+            }),
+            ("select * * from bar", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::From],
+                found: TokenKind::Asterisk,
+                span: at(9, 10),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_select_with_where_clause() {
+        let statement = parse("select name from employee where age > 30 and not name = bob");
+        assert_select_statement(&statement, "employee", &["name"]);
+
+        let s = extract_select(&statement);
+        let predicate = extract_infix_expression(s.where_clause.as_ref().unwrap());
+        assert_eq!(predicate.operator, BinaryOperator::And);
+        assert_eq!(format!("{}", predicate.left), "(age > 30)");
+        assert_eq!(format!("{}", predicate.right), "(NOT (name = bob))");
+
+        assert_eq!(
+            "SELECT name FROM employee WHERE ((age > 30) AND (NOT (name = bob)))",
+            format!("{}", statement),
+        );
+    }
+
+    #[test]
+    fn parse_select_without_where_clause() {
+        let statement = parse("select name from employee");
+        let s = extract_select(&statement);
+        assert!(s.where_clause.is_none());
+    }
+
+    #[test]
+    fn parse_bad_where_clause() {
+        for (input, expected_error) in [
+            ("select name from employee where", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(31, 31) }),
+            ("select name from employee where (a = 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(38, 38),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    fn extract_insert_statement(s: &Statement) -> &InsertStatement {
+        match s {
+            Statement::Insert(i) => i,
+            _ => panic!("{} is not an InsertStatement", s),
+        }
+    }
+
+    #[test]
+    fn parse_insert_statement() {
+        let statement = parse("insert into employee (name, age) values (bob, 30), (alice, 20 + 5)");
+        let i = extract_insert_statement(&statement);
+        assert_token(&i.token, TokenKind::Insert, "INSERT");
+        assert_identifier("employee", &i.table_name);
+        assert_identifier("name", &i.columns[0]);
+        assert_identifier("age", &i.columns[1]);
+        assert_eq!(i.rows.len(), 2);
+        assert_identifier("bob", &i.rows[0][0]);
+        assert_int_literal(30, &i.rows[0][1]);
+        assert_eq!(format!("{}", i.rows[1][1]), "(20 + 5)");
+
+        assert_eq!(
+            "INSERT INTO employee (name, age) VALUES (bob, 30), (alice, (20 + 5))",
+            format!("{}", statement),
+        );
+    }
+
+    #[test]
+    fn parse_insert_statement_without_columns() {
+        let statement = parse("INSERT INTO employee VALUES (1, 2, 3)");
+        let i = extract_insert_statement(&statement);
+        assert!(i.columns.is_empty());
+        assert_eq!(i.rows.len(), 1);
+        assert_eq!("INSERT INTO employee VALUES (1, 2, 3)", format!("{}", statement));
+    }
+
+    #[test]
+    fn parse_bad_insert_statement() {
+        for (input, expected_error) in [
+            ("insert employee values (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Into],
+                found: TokenKind::Identifier,
+                span: at(7, 15),
+            }),
+            ("insert into values (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Values,
+                span: at(12, 18),
+            }),
+            ("insert into employee (1) values (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Int,
+                span: at(22, 23),
+            }),
+            ("insert into employee (name values (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Values,
+                span: at(27, 33),
+            }),
+            ("insert into employee name", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Values],
+                found: TokenKind::Identifier,
+                span: at(21, 25),
+            }),
+            ("insert into employee values", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(27, 27),
+            }),
+            ("insert into employee values ()", ParseError::ExpectedExpression { found: TokenKind::RParen, span: at(29, 30) }),
+            ("insert into employee values (1, 2", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(33, 33),
+            }),
+            ("insert into employee values (1), ", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(33, 33),
+            }),
+            ("insert into employee (a, b) values (1)", ParseError::RowWidthMismatch { expected: 2, found: 1, span: at(36, 37) }),
+            ("insert into employee values (1, 2), (3)", ParseError::RowWidthMismatch { expected: 2, found: 1, span: at(37, 38) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    fn extract_update_statement(s: &Statement) -> &UpdateStatement {
+        match s {
+            Statement::Update(u) => u,
+            _ => panic!("{} is not an UpdateStatement", s),
+        }
+    }
+
+    #[test]
+    fn parse_update_statement() {
+        let statement = parse("update employee set age = age + 1, name = bob where id = 7");
+        let u = extract_update_statement(&statement);
+        assert_token(&u.token, TokenKind::Update, "UPDATE");
+        assert_identifier("employee", &u.table_name);
+        assert_eq!(u.assignments.len(), 2);
+        assert_identifier("age", &u.assignments[0].column);
+        assert_eq!(format!("{}", u.assignments[0].value), "(age + 1)");
+        assert_identifier("name", &u.assignments[1].column);
+        assert_identifier("bob", &u.assignments[1].value);
+        assert_eq!(format!("{}", u.where_clause.as_ref().unwrap()), "(id = 7)");
+
+        assert_eq!(
+            "UPDATE employee SET age = (age + 1), name = bob WHERE (id = 7)",
+            format!("{}", statement),
+        );
+
+        let statement = parse("UPDATE employee SET active = 0");
+        assert!(extract_update_statement(&statement).where_clause.is_none());
+        assert_eq!("UPDATE employee SET active = 0", format!("{}", statement));
+    }
+
+    #[test]
+    fn parse_bad_update_statement() {
+        for (input, expected_error) in [
+            ("update set a = 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Set,
+                span: at(7, 10),
+            }),
+            ("update employee a = 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Set],
+                found: TokenKind::Identifier,
+                span: at(16, 17),
+            }),
+            ("update employee set", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(19, 19),
+            }),
+            ("update employee set a 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Equals],
+                found: TokenKind::Int,
+                span: at(22, 23),
+            }),
+            ("update employee set a =", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(23, 23) }),
+            ("update employee set a = 1,", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(26, 26),
+            }),
+            ("update employee set a = 1 where", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(31, 31) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    fn extract_delete_statement(s: &Statement) -> &DeleteStatement {
+        match s {
+            Statement::Delete(d) => d,
+            _ => panic!("{} is not a DeleteStatement", s),
+        }
+    }
+
+    #[test]
+    fn parse_delete_statement() {
+        let statement = parse("delete from employee where age >= 65 or retired = 1");
+        let d = extract_delete_statement(&statement);
+        assert_token(&d.token, TokenKind::Delete, "DELETE");
+        assert_identifier("employee", &d.table_name);
+        assert_eq!(
+            "DELETE FROM employee WHERE ((age >= 65) OR (retired = 1))",
+            format!("{}", statement),
+        );
+
+        let statement = parse("DELETE FROM employee");
+        assert!(extract_delete_statement(&statement).where_clause.is_none());
+        assert_eq!("DELETE FROM employee", format!("{}", statement));
+    }
+
+    #[test]
+    fn parse_bad_delete_statement() {
+        for (input, expected_error) in [
+            ("delete employee", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::From],
+                found: TokenKind::Identifier,
+                span: at(7, 15),
+            }),
+            ("delete from", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(11, 11),
+            }),
+            ("delete from employee where", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(26, 26) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    fn extract_create_table_statement(s: &Statement) -> &CreateTableStatement {
+        match s {
+            Statement::CreateTable(c) => c,
+            _ => panic!("{} is not a CreateTableStatement", s),
+        }
+    }
+
+    #[test]
+    fn parse_create_table_statement() {
+        let statement = parse("create table employee (id int primary key not null, name varchar(64) not null, \
+            salary decimal(10, 2) default 0, active boolean default 1, started date, notes text)");
+        let c = extract_create_table_statement(&statement);
+        assert_token(&c.token, TokenKind::Create, "CREATE");
+        assert!(!c.if_not_exists);
+        assert_identifier("employee", &c.table_name);
+        assert_eq!(c.columns.len(), 6);
+
+        let id = &c.columns[0];
+        assert_identifier("id", &id.name);
+        assert_eq!(id.data_type, DataType::Int);
+        assert!(id.primary_key && id.not_null);
+        assert!(id.default.is_none());
+
+        let name = &c.columns[1];
+        assert_eq!(name.data_type, DataType::Varchar(Some(64)));
+        assert!(name.not_null && !name.primary_key);
+
+        let salary = &c.columns[2];
+        assert_eq!(salary.data_type, DataType::Decimal { precision: Some(10), scale: Some(2) });
+        assert_int_literal(0, salary.default.as_ref().unwrap());
+
+        assert_eq!(c.columns[3].data_type, DataType::Boolean);
+        assert_eq!(c.columns[4].data_type, DataType::Date);
+        assert_eq!(c.columns[5].data_type, DataType::Text);
+
+        assert_eq!(
+            "CREATE TABLE employee (id INT NOT NULL PRIMARY KEY, name VARCHAR(64) NOT NULL, \
+            salary DECIMAL(10, 2) DEFAULT 0, active BOOLEAN DEFAULT 1, started DATE, notes TEXT)",
+            format!("{}", statement),
+        );
+    }
+
+    #[test]
+    fn parse_key_as_a_name() {
+        // KEY is only a keyword after PRIMARY
+        assert_eq!(
+            "CREATE TABLE kv (key TEXT PRIMARY KEY, value TEXT)",
+            format!("{}", parse("create table kv (key text primary key, value text)")),
+        );
+        assert_select_statement(&parse("select key from kv"), "kv", &["key"]);
+    }
+
+    #[test]
+    fn parse_create_table_if_not_exists() {
+        let statement = parse("CREATE TABLE IF NOT EXISTS counters (n bigint default -1, ratio real)");
+        let c = extract_create_table_statement(&statement);
+        assert!(c.if_not_exists);
+        assert_eq!(c.columns[0].data_type, DataType::BigInt);
+        assert_eq!(c.columns[1].data_type, DataType::Float);
+        assert_eq!(
+            "CREATE TABLE IF NOT EXISTS counters (n BIGINT DEFAULT -1, ratio FLOAT)",
+            format!("{}", statement),
+        );
+    }
+
+    #[test]
+    fn parse_bad_create_table_statement() {
+        for (input, expected_error) in [
+            ("create employee (id int)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Table],
+                found: TokenKind::Identifier,
+                span: at(7, 15),
+            }),
+            ("create table if exists employee (id int)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Not],
+                found: TokenKind::Exists,
+                span: at(16, 22),
+            }),
+            ("create table employee", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(21, 21),
+            }),
+            ("create table employee ()", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::RParen,
+                span: at(23, 24),
+            }),
+            ("create table employee (id)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::RParen,
+                span: at(25, 26),
+            }),
+            ("create table employee (id blob)", ParseError::UnknownDataType { name: "blob".to_string(), span: at(26, 30) }),
+            ("create table employee (id int not)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Null],
+                found: TokenKind::RParen,
+                span: at(33, 34),
+            }),
+            ("create table employee (id int primary)", ParseError::ExpectedKeyword {
+                expected: vec!["KEY"],
+                found: TokenKind::RParen,
+                span: at(37, 38),
+            }),
+            ("create table employee (id int primary \"key\")", ParseError::ExpectedKeyword {
+                expected: vec!["KEY"],
+                found: TokenKind::Identifier,
+                span: at(38, 43),
+            }),
+            ("create table employee (id varchar(x))", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Int],
+                found: TokenKind::Identifier,
+                span: at(34, 35),
+            }),
+            ("create table employee (id int, name text", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(40, 40),
+            }),
+            ("create table employee (id varchar(99999999999))", ParseError::InvalidTypeParameter {
+                literal: "99999999999".to_string(),
+                span: at(34, 45),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_drop_table_statement() {
+        for (input, if_exists, expected) in &[
+            ("drop table employee", false, "DROP TABLE employee"),
+            ("DROP TABLE IF EXISTS employee", true, "DROP TABLE IF EXISTS employee"),
+        ] {
+            let statement = parse(input);
+            match &statement {
+                Statement::DropTable(d) => {
+                    assert_token(&d.token, TokenKind::Drop, "DROP");
+                    assert_eq!(d.if_exists, *if_exists);
+                    assert_identifier("employee", &d.table_name);
+                }
+                _ => panic!("{} is not a DropTableStatement", statement),
+            }
+            assert_eq!(format!("{}", statement), *expected);
+        }
+    }
+
+    fn extract_alter_table_statement(s: &Statement) -> &AlterTableStatement {
+        match s {
+            Statement::AlterTable(a) => a,
+            _ => panic!("{} is not an AlterTableStatement", s),
+        }
+    }
+
+    #[test]
+    fn parse_alter_table_statement() {
+        let statement = parse("alter table employee add column age int not null default 18");
+        let a = extract_alter_table_statement(&statement);
+        assert_token(&a.token, TokenKind::Alter, "ALTER");
+        assert_identifier("employee", &a.table_name);
+        match &a.action {
+            AlterTableAction::AddColumn(column) => {
+                assert_identifier("age", &column.name);
+                assert_eq!(column.data_type, DataType::Int);
+                assert!(column.not_null);
+            }
+            action => panic!("{} is not an ADD COLUMN", action),
+        }
+
+        let statement = parse("alter table employee rename column age to years");
+        match &extract_alter_table_statement(&statement).action {
+            AlterTableAction::RenameColumn { from, to } => {
+                assert_identifier("age", from);
+                assert_identifier("years", to);
+            }
+            action => panic!("{} is not a RENAME COLUMN", action),
+        }
+
+        for (input, expected) in &[
+            ("alter table employee add age int", "ALTER TABLE employee ADD COLUMN age INT"),
+            ("alter table employee drop column age", "ALTER TABLE employee DROP COLUMN age"),
+            ("alter table employee drop age", "ALTER TABLE employee DROP COLUMN age"),
+            ("alter table employee rename column age to years", "ALTER TABLE employee RENAME COLUMN age TO years"),
+            ("alter table employee rename to staff", "ALTER TABLE employee RENAME TO staff"),
+            // ADD, COLUMN, RENAME and TO are only keywords inside ALTER TABLE
+            ("alter table moves add \"to\" int", "ALTER TABLE moves ADD COLUMN \"to\" INT"),
+            ("alter table moves rename column src to \"from\"", "ALTER TABLE moves RENAME COLUMN src TO \"from\""),
+            ("select add, rename, to, column from moves", "SELECT add, rename, to, column FROM moves"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
+    }
+
+    #[test]
+    fn parse_bad_drop_and_alter_table_statement() {
+        for (input, expected_error) in [
+            ("drop employee", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Table],
+                found: TokenKind::Identifier,
+                span: at(5, 13),
+            }),
+            ("drop table if employee", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Exists],
+                found: TokenKind::Identifier,
+                span: at(14, 22),
+            }),
+            ("drop table", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(10, 10),
+            }),
+            ("alter employee add age int", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Table],
+                found: TokenKind::Identifier,
+                span: at(6, 14),
+            }),
+            ("alter table employee", ParseError::ExpectedKeyword {
+                expected: vec!["ADD", "DROP", "RENAME"],
+                found: TokenKind::Eof,
+                span: at(20, 20),
+            }),
+            ("alter table employee modify age int", ParseError::ExpectedKeyword {
+                expected: vec!["ADD", "DROP", "RENAME"],
+                found: TokenKind::Identifier,
+                span: at(21, 27),
+            }),
+            ("alter table employee add column age", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(35, 35),
+            }),
+            ("alter table employee drop column", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(32, 32),
+            }),
+            ("alter table employee rename column age years", ParseError::ExpectedKeyword {
+                expected: vec!["TO"],
+                found: TokenKind::Identifier,
+                span: at(39, 44),
+            }),
+            ("alter table employee rename staff", ParseError::ExpectedKeyword {
+                expected: vec!["TO"],
+                found: TokenKind::Identifier,
+                span: at(28, 33),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_spans() {
+        let input = "select name, age\nfrom employee where -age > 30";
+        let source = |span: Span| &input[span.start..span.end];
+        let statement = parse(input);
+
+        assert_eq!(source(statement.span()), input);
+        assert_eq!(statement.span(), Span { start: 0, end: input.len(), line: 1, column: 1 });
+
+        let s = extract_select(&statement);
+        assert_eq!(s.projection[1].span(), Span { start: 13, end: 16, line: 1, column: 14 });
+        assert_eq!(extract_table_name(s).span(), Span { start: 22, end: 30, line: 2, column: 6 });
+        assert_eq!(s.from.span, Span { start: 17, end: 30, line: 2, column: 1 });
+
+        let predicate = s.where_clause.as_ref().unwrap();
+        assert_eq!(source(predicate.span()), "-age > 30");
+        assert_eq!((predicate.span().line, predicate.span().column), (2, 21));
+
+        let i = extract_infix_expression(predicate);
+        assert_eq!(source(i.left.span()), "-age");
+        assert_eq!(source(i.right.span()), "30");
+
+        let input = "update t set a = b + 1 where c";
+        let source = |span: Span| &input[span.start..span.end];
+        let statement = parse(input);
+        let u = extract_update_statement(&statement);
+        assert_eq!(source(u.span), input);
+        assert_eq!(source(u.assignments[0].span), "a = b + 1");
+
+        let input = "create table t (id int not null, name varchar(10) default 'x')";
+        let source = |span: Span| &input[span.start..span.end];
+        let statement = parse(input);
+        let c = extract_create_table_statement(&statement);
+        assert_eq!(source(c.span), input);
+        assert_eq!(source(c.columns[0].span), "id int not null");
+        assert_eq!(source(c.columns[1].span), "name varchar(10) default 'x'");
+    }
+
+    #[test]
+    fn parse_error_variants() {
+        for (input, expected) in [
+            (")", ParseError::UnknownStatement { found: TokenKind::RParen, span: Span { start: 0, end: 1, line: 1, column: 1 } }),
+            ("1 +", ParseError::ExpectedExpression { found: TokenKind::Eof, span: Span { start: 3, end: 3, line: 1, column: 4 } }),
+            ("create table t (a blob)", ParseError::UnknownDataType {
+                name: "blob".to_string(),
+                span: Span { start: 18, end: 22, line: 1, column: 19 },
+            }),
+            ("99999999999999999999", ParseError::NumberOutOfRange {
+                kind: TokenKind::Int,
+                literal: "99999999999999999999".to_string(),
+                span: Span { start: 0, end: 20, line: 1, column: 1 },
+            }),
+            ("insert into t values (1, 2), (3)", ParseError::RowWidthMismatch {
+                expected: 2,
+                found: 1,
+                span: Span { start: 30, end: 31, line: 1, column: 31 },
+            }),
+        ] {
+            assert_eq!(parse_errors(input), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn parse_error_on_later_line() {
+        assert_eq!(
+            parse_errors("select name\nfrom"),
+            vec![ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: Span { start: 16, end: 16, line: 2, column: 5 },
+            }],
+        );
+    }
+
+    #[test]
+    fn parse_program() {
+        let input = "select a from t; ; delete from t where a = 1;\ndrop table t";
+        let mut p = Parser::new(Lexer::new(input));
+        let program = p.parse_program();
+        assert!(p.errors().is_empty());
+        assert_eq!(
+            program.statements.iter().map(|s| s.to_string()).collect::<Vec<String>>(),
+            vec!["SELECT a FROM t", "DELETE FROM t WHERE (a = 1)", "DROP TABLE t"],
+        );
+        assert_eq!(program.token_literal(), "SELECT");
+        assert_eq!(program.span(), Span { start: 0, end: input.len(), line: 1, column: 1 });
+        assert_eq!(
+            format!("{}", program),
+            "SELECT a FROM t;\nDELETE FROM t WHERE (a = 1);\nDROP TABLE t;",
+        );
+    }
+
+    #[test]
+    fn parse_program_recovers_from_errors() {
+        let script = "create table t (id int, name varchar(x));
+            insert into t values (1, 'a');
+            insert t values (2, 'b');
+            update t set name = 'c' where id = 1;
+            delete from t wher id = 2;
+            select id from t";
+
+        let mut p = Parser::new(Lexer::new(script));
+        let program = p.parse_program();
+
+        assert_eq!(
+            program.statements.iter().map(|s| s.to_string()).collect::<Vec<String>>(),
+            vec!["INSERT INTO t VALUES (1, 'a')", "UPDATE t SET name = 'c' WHERE (id = 1)", "SELECT id FROM t"],
+        );
+        assert_eq!(
+            p.errors(),
+            &[
+                ParseError::UnexpectedToken { expected: vec![TokenKind::Int], found: TokenKind::Identifier, span: at(37, 38) },
+                ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::Into],
+                    found: TokenKind::Identifier,
+                    span: Span { start: 104, end: 105, line: 3, column: 20 },
+                },
+                ParseError::UnexpectedToken {
+                    expected: vec![TokenKind::Semicolon],
+                    found: TokenKind::Identifier,
+                    span: Span { start: 199, end: 203, line: 5, column: 27 },
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_program_stops_at_eof() {
+        let mut p = Parser::new(Lexer::new("select a from t where name = 'oops; select b from t"));
+        let program = p.parse_program();
+        assert!(program.statements.is_empty());
+        assert_eq!(p.errors()[0], ParseError::UnterminatedLiteral { span: at(29, 51) });
+
+        let mut p = Parser::new(Lexer::new(""));
+        let program = p.parse_program();
+        assert!(program.statements.is_empty());
+        assert!(p.errors().is_empty());
+        assert_eq!(program.token_literal(), "");
+        assert_eq!(format!("{}", program), "");
+    }
+
+    #[test]
+    fn parse_select_items() {
+        let statement = parse("select *, e.*, price * 2, -x, 'label' from employee");
+        let s = extract_select(&statement);
+        assert!(matches!(s.projection[0], SelectItem::Wildcard(_)));
+        match &s.projection[1] {
+            SelectItem::QualifiedWildcard { table, span } => {
+                assert_identifier("e", table);
+                assert_eq!((span.start, span.end), (10, 13));
+            }
+            item => panic!("{} is not a qualified wildcard", item),
+        }
+        match &s.projection[2] {
+            SelectItem::Expression { expression, .. } => assert_eq!(extract_infix_expression(expression).operator, BinaryOperator::Multiply),
+            item => panic!("{} is not an expression", item),
+        }
+
+        assert_eq!(
+            "SELECT *, e.*, (price * 2), (-x), 'label' FROM employee",
+            format!("{}", statement),
+        );
+    }
+
+    #[test]
+    fn parse_aliases() {
+        let statement = parse("SELECT price * qty AS total, id ident FROM orders o");
+        let s = extract_select(&statement);
+        match &s.projection[0] {
+            SelectItem::Expression { expression, alias: Some(alias) } => {
+                assert_eq!(format!("{}", expression), "(price * qty)");
+                assert_identifier("total", alias);
+            }
+            item => panic!("{} has no alias", item),
+        }
+        assert_eq!(s.projection[0].span().end, 27);
+        match &s.from.tables[0] {
+            TableReference::Table { alias, .. } => assert_identifier("o", alias.as_ref().unwrap()),
+            table => panic!("{} is not a table", table),
+        }
+
+        assert_eq!(
+            "SELECT (price * qty) AS total, id AS ident FROM orders AS o",
+            format!("{}", statement),
+        );
+        assert_eq!(
+            "SELECT a FROM orders AS o WHERE (a = 1)",
+            format!("{}", parse("select a from orders as o where a = 1")),
+        );
+    }
+
+    #[test]
+    fn parse_bad_aliases() {
+        for (input, expected_error) in [
+            ("select a as from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::From,
+                span: at(12, 16),
+            }),
+            ("select a from t as", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(18, 18),
+            }),
+            ("select a from t as 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Int,
+                span: at(19, 20),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_qualified_and_quoted_identifiers() {
+        let statement = parse("select s.t.first_name, \"Mixed Case\", \"select\", s.\"Order\".* from s.\"Order\" where _id = 1");
+        let s = extract_select(&statement);
+        match &s.projection[0] {
+            SelectItem::Expression { expression: Expression::CompoundIdentifier(c), .. } => {
+                let parts = c.parts.iter().map(|part| part.value.as_str()).collect::<Vec<&str>>();
+                assert_eq!(parts, vec!["s", "t", "first_name"]);
+                assert_eq!((c.span.start, c.span.end), (7, 21));
+            }
+            item => panic!("{} is not a compound identifier", item),
+        }
+        match &s.projection[1] {
+            SelectItem::Expression { expression: Expression::Identifier(i), .. } => {
+                assert_eq!(i.value, "Mixed Case");
+                assert!(i.quoted);
+            }
+            item => panic!("{} is not an identifier", item),
+        }
+        assert!(matches!(s.projection[3], SelectItem::QualifiedWildcard { table: Expression::CompoundIdentifier(_), .. }));
+        assert!(matches!(extract_table_name(s), Expression::CompoundIdentifier(_)));
+
+        assert_eq!(
+            "SELECT s.t.first_name, \"Mixed Case\", \"select\", s.\"Order\".* FROM s.\"Order\" WHERE (_id = 1)",
+            format!("{}", statement),
+        );
+        assert_eq!(format!("{}", parse("a.b + c.d * 2")), "(a.b + (c.d * 2))");
+        assert_eq!(format!("{}", parse("\"a\"\"b\"")), "\"a\"\"b\"");
+
+        let statement = parse("select Foo, foo, FOO, \"Foo\" from t");
+        let values = extract_select(&statement).projection.iter().map(|item| match item {
+            SelectItem::Expression { expression: Expression::Identifier(i), .. } => i.value.as_str(),
+            item => panic!("{} is not an identifier", item),
+        }).collect::<Vec<&str>>();
+        assert_eq!(values, vec!["foo", "foo", "foo", "Foo"]);
+        assert_eq!(format!("{}", statement), "SELECT foo, foo, foo, \"Foo\" FROM t");
+        assert_eq!(
+            format!("{}", parse("update app.users set \"Name\" = 'x'")),
+            "UPDATE app.users SET \"Name\" = 'x'",
+        );
+    }
+
+    #[test]
+    fn parse_bad_qualified_identifiers() {
+        for (input, expected_error) in [
+            ("a.", ParseError::UnexpectedToken { expected: vec![TokenKind::Identifier], found: TokenKind::Eof, span: at(2, 2) }),
+            ("a.*", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Asterisk,
+                span: at(2, 3),
+            }),
+            ("select a from s.", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(16, 16),
+            }),
+            ("select \"abc from t", ParseError::UnterminatedLiteral { span: at(7, 18) }),
+            ("select \"\" from t", ParseError::EmptyIdentifier { span: at(7, 9) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_joins() {
+        let statement = parse("select * from a join b on a.id = b.a_id left outer join c using (id, kind) cross join d, e x");
+        let s = extract_select(&statement);
+        assert_eq!(s.from.tables.len(), 2);
+
+        let cross = match &s.from.tables[0] {
+            TableReference::Join(join) => join,
+            table => panic!("{} is not a join", table),
+        };
+        assert_eq!(cross.kind, JoinKind::Cross);
+        assert!(matches!(cross.constraint, JoinConstraint::None));
+        assert_eq!(format!("{}", cross.right), "d");
+
+        let left = match cross.left.as_ref() {
+            TableReference::Join(join) => join,
+            table => panic!("{} is not a join", table),
+        };
+        assert_eq!(left.kind, JoinKind::Left);
+        assert_token(&left.token, TokenKind::Left, "LEFT");
+        match &left.constraint {
+            JoinConstraint::Using(columns) => {
+                assert_identifier("id", &columns[0]);
+                assert_identifier("kind", &columns[1]);
+            }
+            constraint => panic!("{:?} is not USING", constraint),
+        }
+
+        let inner = match left.left.as_ref() {
+            TableReference::Join(join) => join,
+            table => panic!("{} is not a join", table),
+        };
+        assert_eq!(inner.kind, JoinKind::Inner);
+        assert_eq!((inner.span.start, inner.span.end), (14, 39));
+        match &inner.constraint {
+            JoinConstraint::On(expression) => assert_eq!(format!("{}", expression), "(a.id = b.a_id)"),
+            constraint => panic!("{:?} is not ON", constraint),
+        }
+
+        assert_eq!(
+            "SELECT * FROM a INNER JOIN b ON (a.id = b.a_id) LEFT JOIN c USING (id, kind) CROSS JOIN d, e AS x",
+            format!("{}", statement),
+        );
+
+        for (input, expected) in &[
+            ("select * from a inner join b on x", "SELECT * FROM a INNER JOIN b ON x"),
+            ("select * from a right join b on x", "SELECT * FROM a RIGHT JOIN b ON x"),
+            ("select * from a right outer join b on x", "SELECT * FROM a RIGHT JOIN b ON x"),
+            ("select * from a full outer join b on x where y", "SELECT * FROM a FULL OUTER JOIN b ON x WHERE y"),
+            ("select * from a full join b as c on x", "SELECT * FROM a FULL OUTER JOIN b AS c ON x"),
+            ("select * from a, b, c", "SELECT * FROM a, b, c"),
+            (
+                "select * from a join (b join c on b.y = c.y) on a.x = b.x",
+                "SELECT * FROM a INNER JOIN (b INNER JOIN c ON (b.y = c.y)) ON (a.x = b.x)",
+            ),
+            ("select * from (a join b on x) cross join c", "SELECT * FROM a INNER JOIN b ON x CROSS JOIN c"),
+            ("select * from ((select a from t) as u)", "SELECT * FROM (SELECT a FROM t) AS u"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
+
+        let statement = parse("select * from a join (b join c on b.y = c.y) on a.x = b.x");
+        match &extract_select(&statement).from.tables[0] {
+            TableReference::Join(join) => {
+                assert!(matches!(join.right.as_ref(), TableReference::Join(nested) if nested.span == Span { start: 22, end: 43, line: 1, column: 23 }));
+                assert_eq!(join.span, Span { start: 14, end: 57, line: 1, column: 15 });
+            }
+            table => panic!("{} is not a join", table),
+        }
+    }
+
+    #[test]
+    fn parse_bad_joins() {
+        for (input, expected_error) in [
+            ("select * from a join", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(20, 20),
+            }),
+            ("select * from a join b", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::On, TokenKind::Using],
+                found: TokenKind::Eof,
+                span: at(22, 22),
+            }),
+            ("select * from a inner b on x", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Join],
+                found: TokenKind::Identifier,
+                span: at(22, 23),
+            }),
+            ("select * from a left outer b on x", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Join],
+                found: TokenKind::Identifier,
+                span: at(27, 28),
+            }),
+            ("select * from a cross b", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Join],
+                found: TokenKind::Identifier,
+                span: at(22, 23),
+            }),
+            ("select * from a join b on", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(25, 25) }),
+            ("select * from a join b using id", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Identifier,
+                span: at(29, 31),
+            }),
+            ("select * from a join b using (id", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(32, 32),
+            }),
+            ("select * from a,", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Eof,
+                span: at(16, 16),
+            }),
+            ("select * from a join (b join c on x", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(35, 35),
+            }),
+            ("select * from a join (b join c) on x", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::On, TokenKind::Using],
+                found: TokenKind::RParen,
+                span: at(30, 31),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
     }
 
-    fn parse_expression(&mut self, precedence: Precedence) -> Option<Expression> {
-        let prefix = self.prefix_parsers.get(&self.current_token.kind)?;
-        prefix(self)
+    fn extract_call_expression(e: &Expression) -> &CallExpression {
+        match e {
+            Expression::Call(c) => c,
+            _ => panic!("{} is not a CallExpression", e),
+        }
     }
 
-    fn parse_select_statement(&mut self) -> Option<Statement> {
-        let token = self.current_token.clone();
+    #[test]
+    fn parse_call_expression() {
+        let statement = parse("count(*)");
+        let c = extract_call_expression(&extract_expression_statement(&statement).expression);
+        assert_identifier("count", &c.function);
+        assert!(!c.distinct);
+        assert!(matches!(c.arguments[..], [FunctionArgument::Wildcard(_)]));
+        assert_eq!(c.arguments[0].span(), Span { start: 6, end: 7, line: 1, column: 7 });
 
-        let expressions = self.parse_expression_list()?;
-        if !self.expect_peek(TokenKind::From) {
-            return None;
+        let statement = parse("count(DISTINCT x)");
+        let c = extract_call_expression(&extract_expression_statement(&statement).expression);
+        assert!(c.distinct);
+        assert_eq!(c.span, Span { start: 0, end: 17, line: 1, column: 1 });
+        assert_eq!(c.arguments[0].span(), Span { start: 15, end: 16, line: 1, column: 16 });
+
+        for (input, expected) in &[
+            ("count(*)", "count(*)"),
+            ("count(distinct x)", "count(DISTINCT x)"),
+            ("sum(price * qty)", "sum((price * qty))"),
+            ("now()", "now()"),
+            ("coalesce(a, b, 0) + 1", "(coalesce(a, b, 0) + 1)"),
+            ("-abs(x) * 2", "((-abs(x)) * 2)"),
+            ("pg.lower(upper(name))", "pg.lower(upper(name))"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
         }
+    }
 
-        let table_name = self.parse_table_name()?;
-        Some(Statement::Select(SelectStatement::new(token, table_name, expressions)))
+    #[test]
+    fn parse_bad_call_expression() {
+        for (input, expected_error) in [
+            ("count(", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(6, 6) }),
+            ("count(a b)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Identifier,
+                span: at(8, 9),
+            }),
+            ("count(* x)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Identifier,
+                span: at(8, 9),
+            }),
+            ("count(distinct)", ParseError::ExpectedExpression { found: TokenKind::RParen, span: at(14, 15) }),
+            ("sum(a,)", ParseError::ExpectedExpression { found: TokenKind::RParen, span: at(6, 7) }),
+            ("1(2)", ParseError::NotCallable { span: at(0, 1) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
     }
 
-    fn parse_expression_list(&mut self) -> Option<Vec<Expression>> {
-        let mut expressions = vec![];
+    #[test]
+    fn parse_group_by_and_having() {
+        let statement = parse("select dept, count(*) as n, sum(salary) from employee where active = 1 \
+            group by dept, location having count(*) > 10");
+        let s = extract_select(&statement);
+        assert_eq!(s.group_by.len(), 2);
+        assert_identifier("dept", &s.group_by[0]);
+        assert_identifier("location", &s.group_by[1]);
+        let having = extract_infix_expression(s.having.as_ref().unwrap());
+        assert_eq!(format!("{}", having.left), "count(*)");
 
-        // At least one identifier is required - move to it
-        if !self.expect_peek(TokenKind::Identifier) {
-            return None;
+        assert_eq!(
+            "SELECT dept, count(*) AS n, sum(salary) FROM employee WHERE (active = 1) \
+            GROUP BY dept, location HAVING (count(*) > 10)",
+            format!("{}", statement),
+        );
+        assert_eq!(
+            "SELECT count(*) FROM t HAVING (count(*) > 1)",
+            format!("{}", parse("select count(*) from t having count(*) > 1")),
+        );
+    }
+
+    #[test]
+    fn parse_bad_group_by_and_having() {
+        for (input, expected_error) in [
+            ("select a from t group a", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::By],
+                found: TokenKind::Identifier,
+                span: at(22, 23),
+            }),
+            ("select a from t group by", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(24, 24) }),
+            ("select a from t group by a,", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(27, 27) }),
+            ("select a from t having", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(22, 22) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
         }
+    }
 
-        let identifier = self.parse_identifier()?;
-        expressions.push(identifier);
+    #[test]
+    fn parse_order_by_limit_offset() {
+        let statement = parse("select name from employee order by age desc nulls last, name, salary * 2 asc \
+            limit 10 offset 20");
+        let s = extract_query(&statement);
+        assert_eq!(s.order_by.len(), 3);
+        assert_identifier("age", &s.order_by[0].expression);
+        assert_eq!(s.order_by[0].asc, Some(false));
+        assert_eq!(s.order_by[0].nulls_first, Some(false));
+        assert_eq!(s.order_by[0].span, Span { start: 35, end: 54, line: 1, column: 36 });
+        assert_identifier("name", &s.order_by[1].expression);
+        assert_eq!(s.order_by[1].asc, None);
+        assert_eq!(s.order_by[1].nulls_first, None);
+        assert_eq!(s.order_by[2].asc, Some(true));
+        assert_int_literal(10, s.limit.as_ref().unwrap());
+        assert_int_literal(20, s.offset.as_ref().unwrap());
 
-        // keep collecting expressions until we run out of them
-        while self.peek_token_is(TokenKind::Comma) {
-            // make comma the current token
-            self.next_token();
+        for (input, expected) in &[
+            (
+                "select name from employee order by age desc nulls last, name, salary * 2 asc limit 10 offset 20",
+                "SELECT name FROM employee ORDER BY age DESC NULLS LAST, name, (salary * 2) ASC LIMIT 10 OFFSET 20",
+            ),
+            (
+                "select dept, count(*) from employee group by dept order by count(*) desc limit 5",
+                "SELECT dept, count(*) FROM employee GROUP BY dept ORDER BY count(*) DESC LIMIT 5",
+            ),
+            ("select a from t order by a nulls first", "SELECT a FROM t ORDER BY a NULLS FIRST"),
+            ("select a from t offset 5", "SELECT a FROM t OFFSET 5"),
+            (
+                "select first, last, nulls from people order by last nulls first",
+                "SELECT first, last, nulls FROM people ORDER BY last NULLS FIRST",
+            ),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
+    }
 
-            // an identifier should follow
-            if !self.expect_peek(TokenKind::Identifier) {
-                return None;
+    #[test]
+    fn parse_bad_order_by_limit_offset() {
+        for (input, expected_error) in [
+            ("select a from t order a", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::By],
+                found: TokenKind::Identifier,
+                span: at(22, 23),
+            }),
+            ("select a from t order by", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(24, 24) }),
+            ("select a from t order by a nulls", ParseError::ExpectedKeyword {
+                expected: vec!["FIRST", "LAST"],
+                found: TokenKind::Eof,
+                span: at(32, 32),
+            }),
+            ("select a from t order by a nulls asc", ParseError::ExpectedKeyword {
+                expected: vec!["FIRST", "LAST"],
+                found: TokenKind::Asc,
+                span: at(33, 36),
+            }),
+            ("select a from t limit", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(21, 21) }),
+            ("select a from t limit 1 offset", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(30, 30) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_subqueries() {
+        let statement = parse("select name from employee where dept_id in (select id from dept where active = 1)");
+        let s = extract_select(&statement);
+        match s.where_clause.as_ref().unwrap() {
+            Expression::InSubquery(i) => {
+                assert_identifier("dept_id", &i.expression);
+                assert!(!i.negated);
+                assert_identifier("dept", extract_table_name(extract_query_select(&i.subquery)));
+                assert_eq!(i.span, Span { start: 32, end: 81, line: 1, column: 33 });
             }
+            e => panic!("{} is not an InSubquery", e),
+        }
 
-            expressions.push(self.parse_identifier()?);
+        let statement = parse("a = (select max(age) from employee)");
+        match extract_infix_expression(&extract_expression_statement(&statement).expression).right.as_ref() {
+            Expression::Subquery(s) => {
+                assert_identifier("employee", extract_table_name(extract_query_select(&s.query)));
+                assert_eq!(s.span, Span { start: 4, end: 35, line: 1, column: 5 });
+            }
+            e => panic!("{} is not a Subquery", e),
         }
 
-        Some(expressions)
+        let statement = parse("select * from (select a, b from t) as x where a > 1");
+        let s = extract_select(&statement);
+        match &s.from.tables[0] {
+            TableReference::Derived { subquery, alias, span } => {
+                assert_identifier("t", extract_table_name(extract_query_select(subquery)));
+                assert_identifier("x", alias.as_ref().unwrap());
+                assert_eq!(*span, Span { start: 14, end: 39, line: 1, column: 15 });
+            }
+            t => panic!("{} is not a derived table", t),
+        }
+
+        for (input, expected) in &[
+            (
+                "select name, (select count(*) from orders o where o.customer_id = c.id) as n from customer c",
+                "SELECT name, (SELECT count(*) FROM orders AS o WHERE (o.customer_id = c.id)) AS n FROM customer AS c",
+            ),
+            (
+                "select a from t where a not in (select b from u) and exists (select 1 from v)",
+                "SELECT a FROM t WHERE ((a NOT IN (SELECT b FROM u)) AND EXISTS (SELECT 1 FROM v))",
+            ),
+            (
+                "select a from t where not exists (select 1 from u where u.a = t.a)",
+                "SELECT a FROM t WHERE (NOT EXISTS (SELECT 1 FROM u WHERE (u.a = t.a)))",
+            ),
+            ("a + 1 in (select b from u)", "((a + 1) IN (SELECT b FROM u))"),
+            ("a = b in (select b from u)", "(a = (b IN (SELECT b FROM u)))"),
+            (
+                "select * from (select a from t order by a limit 1) x join u on x.a = u.a",
+                "SELECT * FROM (SELECT a FROM t ORDER BY a LIMIT 1) AS x INNER JOIN u ON (x.a = u.a)",
+            ),
+            ("select * from (select a from t)", "SELECT * FROM (SELECT a FROM t)"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
+
+        // a default value stops before NOT so the constraint after it still parses
+        assert_eq!(
+            "CREATE TABLE t (a INT NOT NULL DEFAULT 1)",
+            format!("{}", parse("create table t (a int default 1 not null)")),
+        );
     }
 
-    fn parse_table_name(&mut self) -> Option<Expression> {
-        if !self.expect_peek(TokenKind::Identifier) {
-            return None;
+    #[test]
+    fn parse_bad_subqueries() {
+        for (input, expected_error) in [
+            ("a in b", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Identifier,
+                span: at(5, 6),
+            }),
+            ("a not is null", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::In, TokenKind::Between, TokenKind::Like],
+                found: TokenKind::Is,
+                span: at(6, 8),
+            }),
+            ("exists a", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Identifier,
+                span: at(7, 8),
+            }),
+            ("exists (select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(23, 23),
+            }),
+            ("(select a from)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::RParen,
+                span: at(14, 15),
+            }),
+            ("select a from (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Int,
+                span: at(15, 16),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+    }
+
+    #[test]
+    fn parse_common_table_expressions() {
+        let statement = parse("with recursive reports (id, manager_id) as (select id, manager_id from employee), \
+            managers as (select manager_id from reports) select * from managers");
+        if let Statement::Query(q) = &statement {
+            let with = q.with.as_ref().unwrap();
+            assert_token(&with.token, TokenKind::With, "WITH");
+            assert!(with.recursive);
+            assert_eq!(with.ctes.len(), 2);
+            assert_identifier("reports", &with.ctes[0].name);
+            assert_eq!(with.ctes[0].columns.len(), 2);
+            assert_identifier("manager_id", &with.ctes[0].columns[1]);
+            assert_identifier("employee", extract_table_name(extract_query_select(&with.ctes[0].query)));
+            assert_identifier("managers", &with.ctes[1].name);
+            assert!(with.ctes[1].columns.is_empty());
+            assert_identifier("managers", extract_table_name(extract_query_select(q)));
+            assert_eq!(with.span, Span { start: 0, end: 126, line: 1, column: 1 });
+            assert_eq!(with.ctes[0].span, Span { start: 15, end: 80, line: 1, column: 16 });
+            assert_eq!(with.ctes[1].span, Span { start: 82, end: 126, line: 1, column: 83 });
+            assert_eq!(q.span, Span { start: 0, end: 149, line: 1, column: 1 });
+        } else {
+            panic!("{} is not a query", statement);
+        }
+
+        for (input, expected) in &[
+            (
+                "with t as (select a from u) select a from t",
+                "WITH t AS (SELECT a FROM u) SELECT a FROM t",
+            ),
+            (
+                "WITH RECURSIVE chain (id, boss) AS (select id, boss from org where boss = 1) select id from chain",
+                "WITH RECURSIVE chain (id, boss) AS (SELECT id, boss FROM org WHERE (boss = 1)) SELECT id FROM chain",
+            ),
+            (
+                "select * from (with t as (select a from u) select a from t) x where a in (with v as (select 1 from w) select * from v)",
+                "SELECT * FROM (WITH t AS (SELECT a FROM u) SELECT a FROM t) AS x \
+                WHERE (a IN (WITH v AS (SELECT 1 FROM w) SELECT * FROM v))",
+            ),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
         }
 
-        self.parse_identifier()
+        let statement = parse("select a from t");
+        if let Statement::Query(q) = &statement {
+            assert!(q.with.is_none());
+        }
     }
 
-    fn parse_identifier(&mut self) -> Option<Expression> {
-        let identifier = Identifier {
-            token: self.current_token.clone(),
-            value: self.current_token.literal.clone(),
-        };
-        Some(Expression::Identifier(identifier))
+    #[test]
+    fn parse_bad_common_table_expressions() {
+        for (input, expected_error) in [
+            ("with select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Select,
+                span: at(5, 11),
+            }),
+            ("with t select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::As],
+                found: TokenKind::Select,
+                span: at(7, 13),
+            }),
+            ("with t as select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Select,
+                span: at(10, 16),
+            }),
+            ("with t as (select a from u)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(27, 27),
+            }),
+            ("with t as (select a from u), select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Select,
+                span: at(29, 35),
+            }),
+            ("with t () as (select a from u) select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::RParen,
+                span: at(8, 9),
+            }),
+            ("with t as (insert into u values (1)) select a from t", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::With, TokenKind::LParen],
+                found: TokenKind::Insert,
+                span: at(11, 17),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
     }
 
-    fn parse_integer_literal(&mut self) -> Option<Expression> {
-        let literal = IntLiteral {
-            token: self.current_token.clone(),
-            value: self.current_token.literal.parse().ok()?,
-        };
-        Some(Expression::Int(literal))
+    fn extract_set_operation(body: &SetExpression) -> &SetOperation {
+        match body {
+            SetExpression::Operation(operation) => operation,
+            _ => panic!("{} is not a set operation", body),
+        }
     }
 
-    fn parse_prefix_expression(&mut self) -> Option<Expression> {
-        let token = self.current_token.clone();
-        let operator = token.literal.clone();
+    #[test]
+    fn parse_set_operations() {
+        let statement = parse("select a from t union all select a from u intersect select a from v \
+            except select a from w order by a limit 5");
+        let q = extract_query(&statement);
+        assert_eq!(q.order_by.len(), 1);
+        assert_int_literal(5, q.limit.as_ref().unwrap());
 
-        self.next_token();
+        // ((t UNION ALL (u INTERSECT v)) EXCEPT w)
+        let except = extract_set_operation(&q.body);
+        assert_eq!(except.operator, SetOperator::Except);
+        assert!(!except.all);
+        assert_identifier("w", extract_table_name(match &*except.right {
+            SetExpression::Select(select) => select,
+            body => panic!("{} is not a plain select", body),
+        }));
 
-        let right = Box::new(self.parse_expression(Precedence::Prefix)?);
-        let prefix_expression = Expression::Prefixed(PrefixExpression { token, operator, right });
-        Some(prefix_expression)
-    }
-}
+        let union = extract_set_operation(&except.left);
+        assert_eq!(union.operator, SetOperator::Union);
+        assert!(union.all);
+        assert_token(&union.token, TokenKind::Union, "UNION");
+        assert_eq!(union.span, Span { start: 0, end: 67, line: 1, column: 1 });
 
-// Initializers
-impl Parser {
-    pub fn new(mut lexer: Lexer) -> Self {
-        let current_token = lexer.next_token();
-        let peek_token = lexer.next_token();
-        let mut p = Parser {
-            lexer,
-            current_token,
-            peek_token,
-            errors: vec![],
-            prefix_parsers: Default::default(),
-            infix_parsers: Default::default(),
-        };
+        let intersect = extract_set_operation(&union.right);
+        assert_eq!(intersect.operator, SetOperator::Intersect);
 
-        p.register_prefix(TokenKind::Identifier, Parser::parse_identifier);
-        p.register_prefix(TokenKind::Int, Parser::parse_integer_literal);
-        p.register_prefix(TokenKind::Bang, Parser::parse_prefix_expression);
-        p.register_prefix(TokenKind::Minus, Parser::parse_prefix_expression);
-        p
-    }
+        for (input, expected) in &[
+            ("select a from t union select b from u", "SELECT a FROM t UNION SELECT b FROM u"),
+            (
+                "select a from t except all select a from u order by a desc",
+                "SELECT a FROM t EXCEPT ALL SELECT a FROM u ORDER BY a DESC",
+            ),
+            (
+                "select a from t intersect (select a from u union select a from v order by a limit 1)",
+                "SELECT a FROM t INTERSECT (SELECT a FROM u UNION SELECT a FROM v ORDER BY a LIMIT 1)",
+            ),
+            (
+                "with x as (select a from t union select a from u) select a from x union select 1 from y",
+                "WITH x AS (SELECT a FROM t UNION SELECT a FROM u) SELECT a FROM x UNION SELECT 1 FROM y",
+            ),
+            (
+                "select a from t where a in (select b from u union select c from v)",
+                "SELECT a FROM t WHERE (a IN (SELECT b FROM u UNION SELECT c FROM v))",
+            ),
+            ("(select a from t) union (select b from u)", "(SELECT a FROM t) UNION (SELECT b FROM u)"),
+            ("(select a from t) order by a limit 1", "(SELECT a FROM t) ORDER BY a LIMIT 1"),
+            (
+                "select a from t where a in ((select a from t) union (select b from u))",
+                "SELECT a FROM t WHERE (a IN ((SELECT a FROM t) UNION (SELECT b FROM u)))",
+            ),
+            (
+                "select ((select a from t) except (select b from u)) * 2 from v",
+                "SELECT (((SELECT a FROM t) EXCEPT (SELECT b FROM u)) * 2) FROM v",
+            ),
+            ("select a from t where a in ((select b from u), 1)", "SELECT a FROM t WHERE (a IN ((SELECT b FROM u), 1))"),
+            (
+                "select * from ((select a from t) union (select b from u)) x",
+                "SELECT * FROM ((SELECT a FROM t) UNION (SELECT b FROM u)) AS x",
+            ),
+            (
+                "with c as ((select a from t) union (select b from u)) select * from c",
+                "WITH c AS ((SELECT a FROM t) UNION (SELECT b FROM u)) SELECT * FROM c",
+            ),
+            (
+                "exists ((select a from t) union (select b from u))",
+                "EXISTS ((SELECT a FROM t) UNION (SELECT b FROM u))",
+            ),
+            (
+                "select a from t union ((select b from u) union (select c from v))",
+                "SELECT a FROM t UNION ((SELECT b FROM u) UNION (SELECT c FROM v))",
+            ),
+            (
+                "with c as (select 1 from t) (select a from c) union (select b from c)",
+                "WITH c AS (SELECT 1 FROM t) (SELECT a FROM c) UNION (SELECT b FROM c)",
+            ),
+            ("select ((select a from t) + 1) from u", "SELECT ((SELECT a FROM t) + 1) FROM u"),
+            ("select * from ((select a from t) x join u on x.a = u.a)", "SELECT * FROM (SELECT a FROM t) AS x INNER JOIN u ON (x.a = u.a)"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
 
-    fn register_prefix(&mut self, kind: TokenKind, prefix_parser: PrefixParser) {
-        self.prefix_parsers.insert(kind, prefix_parser);
-    }
+        let statement = parse("(select a from t) union (select b from u)");
+        let q = extract_query(&statement);
+        assert_token(&q.token, TokenKind::LParen, "(");
+        assert_eq!(q.span, Span { start: 0, end: 41, line: 1, column: 1 });
+        assert_eq!(extract_set_operation(&q.body).operator, SetOperator::Union);
 
-    fn register_infix(&mut self, kind: TokenKind, infix_parser: InfixParser) {
-        self.infix_parsers.insert(kind, infix_parser);
-    }
-}
+        let statement = parse("(select a from t)");
+        assert!(matches!(extract_query(&statement).body, SetExpression::Query(_)));
 
-// Token Helpers
-impl Parser {
-    fn next_token(&mut self) {
-        self.current_token = self.peek_token.clone();
-        self.peek_token = self.lexer.next_token();
+        let statement = parse("select a from t where a in ((select a from t) union (select b from u))");
+        match extract_select(&statement).where_clause.as_ref().unwrap() {
+            Expression::InSubquery(i) => assert_eq!(extract_set_operation(&i.subquery.body).operator, SetOperator::Union),
+            e => panic!("{} is not an IN subquery", e),
+        }
     }
 
-    fn current_token_is(&self, kind: TokenKind) -> bool {
-        self.current_token.kind == kind
+    #[test]
+    fn parse_bad_set_operations() {
+        for (input, expected_error) in [
+            ("select a from t union", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(21, 21),
+            }),
+            ("select a from t union all all select a from u", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::LParen],
+                found: TokenKind::All,
+                span: at(26, 29),
+            }),
+            ("select a from t intersect insert", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::LParen],
+                found: TokenKind::Insert,
+                span: at(26, 32),
+            }),
+            ("select a from t union (select a from u", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(38, 38),
+            }),
+            ("select a from t union (1)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::With, TokenKind::LParen],
+                found: TokenKind::Int,
+                span: at(23, 24),
+            }),
+            ("(select a from t) union", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Select, TokenKind::LParen],
+                found: TokenKind::Eof,
+                span: at(23, 23),
+            }),
+            ("select a from t where a in ((select a from t) union (select b from u)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(69, 69),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
     }
 
-    fn peek_token_is(&self, kind: TokenKind) -> bool {
-        self.peek_token.kind == kind
-    }
+    #[test]
+    fn parse_literal_keywords() {
+        let statement = parse("true");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Boolean(b) => assert!(b.value),
+            e => panic!("{} is not a boolean", e),
+        }
 
-    fn expect_peek(&mut self, kind: TokenKind) -> bool {
-        if self.peek_token_is(kind) {
-            self.next_token();
-            true
-        } else {
-            self.errors.push(format!("expected {:?}, found {:?}", kind, self.peek_token.kind));
-            false
+        let statement = parse("FALSE");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Boolean(b) => assert!(!b.value),
+            e => panic!("{} is not a boolean", e),
+        }
+
+        let statement = parse("null");
+        assert!(matches!(extract_expression_statement(&statement).expression, Expression::Null(_)));
+
+        for (input, expected) in &[
+            ("not true", "(NOT TRUE)"),
+            ("insert into t values (null, false)", "INSERT INTO t VALUES (NULL, FALSE)"),
+            ("create table t (a boolean default true not null)", "CREATE TABLE t (a BOOLEAN NOT NULL DEFAULT TRUE)"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::fmt::Debug;
+    #[test]
+    fn parse_predicates() {
+        let statement = parse("a not between 1 and 10");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Between(b) => {
+                assert_identifier("a", &b.expression);
+                assert!(b.negated);
+                assert_int_literal(1, &b.low);
+                assert_int_literal(10, &b.high);
+                assert_eq!(b.span, Span { start: 0, end: 22, line: 1, column: 1 });
+            }
+            e => panic!("{} is not a BETWEEN", e),
+        }
 
-    use crate::lexer::TokenKind::Int;
+        let statement = parse("a in (1, 2, 3)");
+        match &extract_expression_statement(&statement).expression {
+            Expression::InList(i) => {
+                assert!(!i.negated);
+                assert_eq!(i.list.len(), 3);
+                assert_int_literal(3, &i.list[2]);
+            }
+            e => panic!("{} is not an IN list", e),
+        }
 
-    use super::*;
+        let statement = parse("name like 'a!%%' escape '!'");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Like(l) => {
+                assert!(!l.negated);
+                assert_eq!(format!("{}", l.pattern), "'a!%%'");
+                assert_eq!(format!("{}", l.escape.as_ref().unwrap()), "'!'");
+            }
+            e => panic!("{} is not a LIKE", e),
+        }
 
-    fn parse(s: &str) -> Statement {
-        let mut parser = Parser::new(Lexer::new(s));
-        let statement = parser.parse_statement();
-        assert!(parser.errors.is_empty());
-        statement.unwrap()
-    }
+        let statement = parse("a is not null");
+        match &extract_expression_statement(&statement).expression {
+            Expression::IsNull(i) => {
+                assert!(i.negated);
+                assert_eq!(i.span, Span { start: 0, end: 13, line: 1, column: 1 });
+            }
+            e => panic!("{} is not an IS NULL", e),
+        }
 
-    fn assert_identifier(s: &str, expression: &Expression) {
-        match expression {
-            Expression::Identifier(Identifier { value, .. }) => assert_eq!(value, s),
-            _ => panic!("{} is not an identifier", expression)
+        for (input, expected) in &[
+            ("a between 1 and 2 and b", "((a BETWEEN 1 AND 2) AND b)"),
+            ("a + 1 between b - 1 and b * 2", "((a + 1) BETWEEN (b - 1) AND (b * 2))"),
+            ("a not in ('x', 'y') or b in (1)", "((a NOT IN ('x', 'y')) OR (b IN (1)))"),
+            ("name not like 'bob%'", "(name NOT LIKE 'bob%')"),
+            ("a = 1 is null", "((a = 1) IS NULL)"),
+            ("not a is null", "(NOT (a IS NULL))"),
+            ("a is null and b is not null", "((a IS NULL) AND (b IS NOT NULL))"),
+            (
+                "select a from t where b not between 1 and 2 and c like 'x' escape '\\'",
+                "SELECT a FROM t WHERE ((b NOT BETWEEN 1 AND 2) AND (c LIKE 'x' ESCAPE '\\'))",
+            ),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
         }
     }
 
-    fn assert_int_literal(value: i64, expression: &Expression) {
-        if let Expression::Int(i) = expression {
-            assert_eq!(i.value, value);
-        } else {
-            panic!("{} is not an int literal", expression)
+    #[test]
+    fn parse_case_expression() {
+        let statement = parse("case when a > 1 then 'big' when a > 0 then 'small' else 'none' end");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Case(c) => {
+                assert!(c.operand.is_none());
+                assert_eq!(c.when_clauses.len(), 2);
+                assert_eq!(format!("{}", c.when_clauses[0].condition), "(a > 1)");
+                assert_eq!(c.when_clauses[1].span, Span { start: 27, end: 50, line: 1, column: 28 });
+                assert!(c.else_result.is_some());
+                assert_eq!(c.span, Span { start: 0, end: 66, line: 1, column: 1 });
+            }
+            e => panic!("{} is not a CASE", e),
         }
-    }
 
-    fn assert_token(token: &Token, kind: TokenKind, literal: &str) {
-        assert_eq!(token.kind, kind);
-        assert_eq!(token.literal, literal);
+        let statement = parse("case status when 1 then 'on' end");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Case(c) => {
+                assert_identifier("status", c.operand.as_ref().unwrap());
+                assert_eq!(c.when_clauses.len(), 1);
+                assert!(c.else_result.is_none());
+            }
+            e => panic!("{} is not a CASE", e),
+        }
+
+        for (input, expected) in &[
+            (
+                "select case when a is null then 0 else a end as b from t",
+                "SELECT CASE WHEN (a IS NULL) THEN 0 ELSE a END AS b FROM t",
+            ),
+            ("case a + 1 when 2 then true end = b", "(CASE (a + 1) WHEN 2 THEN TRUE END = b)"),
+            ("select end, escape, to from t", "SELECT end, escape, to FROM t"),
+            ("case when end then escape else end end", "CASE WHEN end THEN escape ELSE end END"),
+            ("a like escape escape '!'", "(a LIKE escape ESCAPE '!')"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
     }
 
-    fn assert_select_statement(statement: &Statement, table_name: &str, attributes: &[&str]) {
-        if let Statement::Select(s) = statement {
-            assert_token(&s.token, TokenKind::Select, "SELECT");
-            assert_identifier(table_name, &s.table_name);
-            for (expect, got) in attributes.iter().zip(s.expressions.iter()) {
-                assert_identifier(expect, got);
-            }
-        } else {
-            panic!("{:?} is not a select statement", statement)
+    #[test]
+    fn parse_bad_predicates() {
+        for (input, expected_error) in [
+            ("a between 1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::And],
+                found: TokenKind::Eof,
+                span: at(11, 11),
+            }),
+            ("a between 1 or 2", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::And],
+                found: TokenKind::Or,
+                span: at(12, 14),
+            }),
+            ("a in ()", ParseError::ExpectedExpression { found: TokenKind::RParen, span: at(6, 7) }),
+            ("a in (1, 2", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(10, 10),
+            }),
+            ("a like", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(6, 6) }),
+            ("a like 'x' escape", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(17, 17) }),
+            ("a is 1", ParseError::UnexpectedToken { expected: vec![TokenKind::Null], found: TokenKind::Int, span: at(5, 6) }),
+            ("a is not", ParseError::UnexpectedToken { expected: vec![TokenKind::Null], found: TokenKind::Eof, span: at(8, 8) }),
+            ("case end", ParseError::UnexpectedToken { expected: vec![TokenKind::When], found: TokenKind::Eof, span: at(8, 8) }),
+            ("case a end", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::When],
+                found: TokenKind::Identifier,
+                span: at(7, 10),
+            }),
+            ("case when a 1 end", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Then],
+                found: TokenKind::Int,
+                span: at(12, 13),
+            }),
+            ("case when a then 1", ParseError::ExpectedKeyword {
+                expected: vec!["END"],
+                found: TokenKind::Eof,
+                span: at(18, 18),
+            }),
+            ("case when a then 1 else end", ParseError::ExpectedKeyword {
+                expected: vec!["END"],
+                found: TokenKind::Eof,
+                span: at(27, 27),
+            }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
         }
     }
 
     #[test]
-    fn parse_select_statement() {
-        struct Test<'a> {
-            input: &'a str,
-            table_name: &'a str,
-            expressions: &'a [&'a str],
+    fn parse_cast_expression() {
+        let statement = parse("cast(price * 2 as decimal(10, 2))");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Cast(c) => {
+                assert_token(&c.token, TokenKind::Cast, "CAST");
+                assert_eq!(format!("{}", c.expression), "(price * 2)");
+                assert_eq!(c.data_type, DataType::Decimal { precision: Some(10), scale: Some(2) });
+                assert_eq!(c.span, Span { start: 0, end: 33, line: 1, column: 1 });
+            }
+            e => panic!("{} is not a CAST", e),
         }
 
-        for t in &[
-            Test { input: "select name, age, gender from employee", table_name: "employee", expressions: &["name", "age", "gender"] },
-            Test { input: "select gender from foobar", table_name: "foobar", expressions: &["gender"] },
+        let statement = parse("'2024-01-01'::date");
+        match &extract_expression_statement(&statement).expression {
+            Expression::Cast(c) => {
+                assert_token(&c.token, TokenKind::DoubleColon, "::");
+                assert_eq!(c.data_type, DataType::Date);
+                assert_eq!(c.span, Span { start: 0, end: 18, line: 1, column: 1 });
+            }
+            e => panic!("{} is not a CAST", e),
+        }
+
+        for (input, expected) in &[
+            ("cast(a as text)", "CAST(a AS TEXT)"),
+            ("a::varchar(20)", "CAST(a AS VARCHAR(20))"),
+            ("-a::int", "(-CAST(a AS INT))"),
+            ("a + b::float * 2", "(a + (CAST(b AS FLOAT) * 2))"),
+            ("a::text::int", "CAST(CAST(a AS TEXT) AS INT)"),
+            ("count(*)::bigint", "CAST(count(*) AS BIGINT)"),
+            (
+                "select cast(created as date) as day from t where id::text like '1%'",
+                "SELECT CAST(created AS DATE) AS day FROM t WHERE (CAST(id AS TEXT) LIKE '1%')",
+            ),
         ] {
-            let statement = parse(t.input);
-            println!("{}", statement);
-            assert_select_statement(&statement, t.table_name, t.expressions);
+            assert_eq!(format!("{}", parse(input)), *expected);
         }
     }
 
     #[test]
-    fn parse_bad_select_statement() {
-        for (input, expected_error) in &[
-            ("select foo from", "expected Identifier, found Eof"),
-            ("select blah", "expected From, found Eof"),
-            ("select from bar", "expected Identifier, found From"),
+    fn parse_bad_cast_expression() {
+        for (input, expected_error) in [
+            ("cast a as int", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::LParen],
+                found: TokenKind::Identifier,
+                span: at(5, 6),
+            }),
+            ("cast(a int)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::As],
+                found: TokenKind::Identifier,
+                span: at(7, 10),
+            }),
+            ("cast(a as)", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::RParen,
+                span: at(9, 10),
+            }),
+            ("cast(a as blob)", ParseError::UnknownDataType { name: "blob".to_string(), span: at(10, 14) }),
+            ("cast(a as int", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::RParen],
+                found: TokenKind::Eof,
+                span: at(13, 13),
+            }),
+            ("a::", ParseError::UnexpectedToken { expected: vec![TokenKind::Identifier], found: TokenKind::Eof, span: at(3, 3) }),
+            ("a::1", ParseError::UnexpectedToken {
+                expected: vec![TokenKind::Identifier],
+                found: TokenKind::Int,
+                span: at(3, 4),
+            }),
         ] {
-            let mut p = Parser::new(Lexer::new(input));
-            let statement = p.parse_statement();
-            assert!(statement.is_none());
-            let error = &p.errors[0];
-            assert_eq!(error, expected_error);
+            assert_eq!(parse_errors(input)[0], expected_error);
         }
     }
 
@@ -270,12 +3434,7 @@ mod tests {
     #[test]
     fn parse_identifier() {
         let statement = parse("somename;");
-        let expected = String::from("somename");
-        assert!(matches!(statement, Statement::Expr(ExpressionStatement {
-            expression: Expression::Identifier(Identifier {
-                value: expected, ..
-            }), ..
-        })));
+        assert_identifier("somename", &extract_expression_statement(&statement).expression);
     }
 
     #[test]
@@ -302,16 +3461,194 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_float() {
+        for (input, value, expected) in &[
+            ("1.5", 1.5, "1.5"),
+            (".5", 0.5, "0.5"),
+            ("1e-3", 0.001, "0.001"),
+            ("2E+10", 2e10, "20000000000.0"),
+            ("3e300", 3e300, "3e300"),
+        ] {
+            let statement = parse(input);
+            match &extract_expression_statement(&statement).expression {
+                Expression::Float(f) => assert_eq!(f.value, *value),
+                e => panic!("{} is not a float literal", e),
+            }
+            assert_eq!(format!("{}", statement), *expected);
+        }
+    }
+
+    #[test]
+    fn parse_decimal() {
+        for (input, value, scale, expected) in &[
+            ("DECIMAL '19.99'", 1999, 2, "DECIMAL '19.99'"),
+            ("numeric '0.050'", 50, 3, "DECIMAL '0.050'"),
+            ("decimal '.5'", 5, 1, "DECIMAL '0.5'"),
+            ("DECIMAL '42'", 42, 0, "DECIMAL '42'"),
+            ("DECIMAL '-0.05'", -5, 2, "DECIMAL '-0.05'"),
+            ("DECIMAL '+7.'", 7, 0, "DECIMAL '7'"),
+        ] {
+            let statement = parse(input);
+            match &extract_expression_statement(&statement).expression {
+                Expression::Decimal(d) => {
+                    assert_eq!(d.value, *value);
+                    assert_eq!(d.scale, *scale);
+                }
+                e => panic!("{} is not a decimal literal", e),
+            }
+            assert_eq!(format!("{}", statement), *expected);
+        }
+
+        assert_eq!(format!("{}", parse("price * 1.5 + DECIMAL '0.25'")), "((price * 1.5) + DECIMAL '0.25')");
+        assert_eq!(format!("{}", parse("select decimal from t")), "SELECT decimal FROM t");
+    }
+
+    #[test]
+    fn parse_out_of_range_numbers() {
+        for (input, expected_error) in [
+            ("9223372036854775808", ParseError::NumberOutOfRange {
+                kind: TokenKind::Int,
+                literal: "9223372036854775808".to_string(),
+                span: at(0, 19),
+            }),
+            ("select a from t where a > 99999999999999999999", ParseError::NumberOutOfRange {
+                kind: TokenKind::Int,
+                literal: "99999999999999999999".to_string(),
+                span: at(26, 46),
+            }),
+            ("1e999", ParseError::NumberOutOfRange { kind: TokenKind::Float, literal: "1e999".to_string(), span: at(0, 5) }),
+            ("-9223372036854775809", ParseError::NumberOutOfRange {
+                kind: TokenKind::Int,
+                literal: "-9223372036854775809".to_string(),
+                span: at(0, 20),
+            }),
+            ("DECIMAL '1701411834604692317316873037158841057280'", ParseError::InvalidDecimal {
+                literal: "1701411834604692317316873037158841057280".to_string(),
+                span: at(8, 50),
+            }),
+            ("DECIMAL '1.2.3'", ParseError::InvalidDecimal { literal: "1.2.3".to_string(), span: at(8, 15) }),
+            ("DECIMAL '.'", ParseError::InvalidDecimal { literal: ".".to_string(), span: at(8, 11) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
+
+        assert_int_literal(i64::MAX, &extract_expression_statement(&parse("9223372036854775807")).expression);
+
+        let statement = parse("select a from t where a = -9223372036854775808");
+        let where_clause = extract_select(&statement).where_clause.as_ref().unwrap();
+        let comparison = extract_infix_expression(where_clause);
+        assert_int_literal(i64::MIN, &comparison.right);
+        assert_eq!(comparison.right.span(), Span { start: 26, end: 46, line: 1, column: 27 });
+    }
+
+    #[test]
+    fn parse_string() {
+        let statement = parse("'it''s'");
+        match &extract_expression_statement(&statement).expression {
+            Expression::String(s) => assert_eq!(s.value, "it's"),
+            e => panic!("{} is not a string literal", e),
+        }
+        assert_eq!(format!("{}", statement), "'it''s'");
+
+        let statement = parse("select name from employee where name = 'bob'");
+        assert_eq!(format!("{}", statement), "SELECT name FROM employee WHERE (name = 'bob')");
+
+        let statement = parse("insert into employee (name) values ('O''Brien')");
+        assert_eq!(format!("{}", statement), "INSERT INTO employee (name) VALUES ('O''Brien')");
+    }
+
+    #[test]
+    fn parse_unterminated_string() {
+        let errors = parse_errors("select name from employee where name = 'bob");
+        assert_eq!(errors[0], ParseError::UnterminatedLiteral { span: at(39, 43) });
+        assert_eq!(errors[1], ParseError::ExpectedExpression { found: TokenKind::Illegal, span: at(39, 43) });
+    }
+
     #[test]
     fn parse_prefix_expression() {
         let statement = parse("!1");
         let p = extract_prefix_expression(extract_expression_statement(&statement));
-        assert_eq!(p.operator, "!");
+        assert_eq!(p.operator, UnaryOperator::Not);
         assert_int_literal(1, &p.right);
 
         let statement = parse("-foobar");
         let p = extract_prefix_expression(extract_expression_statement(&statement));
-        assert_eq!(p.operator, "-");
+        assert_eq!(p.operator, UnaryOperator::Minus);
         assert_identifier("foobar", &p.right);
+
+        // `--` starts a comment, so printing has to keep two minus signs apart for the output to parse again
+        for (input, expected) in &[("-(-1)", "(- -1)"), ("- -1", "(- -1)"), ("-(-a)", "(-(-a))"), ("1 - -1", "(1 - -1)")] {
+            let printed = format!("{}", parse(input));
+            assert_eq!(printed, *expected);
+            assert_eq!(format!("{}", parse(&printed)), printed);
+        }
+    }
+
+    fn extract_infix_expression(e: &Expression) -> &InfixExpression {
+        match e {
+            Expression::Infixed(i) => i,
+            _ => panic!("{} is not an InfixExpression", e),
+        }
+    }
+
+    #[test]
+    fn parse_infix_expression() {
+        for (input, left, operator, right) in &[
+            ("1 + 2", 1, BinaryOperator::Plus, 2),
+            ("1 - 2", 1, BinaryOperator::Minus, 2),
+            ("1 * 2", 1, BinaryOperator::Multiply, 2),
+            ("1 / 2", 1, BinaryOperator::Divide, 2),
+            ("1 = 2", 1, BinaryOperator::Eq, 2),
+            ("1 != 2", 1, BinaryOperator::NotEq, 2),
+            ("1 < 2", 1, BinaryOperator::Lt, 2),
+            ("1 > 2", 1, BinaryOperator::Gt, 2),
+            ("1 <= 2", 1, BinaryOperator::LtEq, 2),
+            ("1 >= 2", 1, BinaryOperator::GtEq, 2),
+            ("1 and 2", 1, BinaryOperator::And, 2),
+            ("1 OR 2", 1, BinaryOperator::Or, 2),
+        ] {
+            let statement = parse(input);
+            let i = extract_infix_expression(&extract_expression_statement(&statement).expression);
+            assert_int_literal(*left, &i.left);
+            assert_eq!(i.operator, *operator);
+            assert_int_literal(*right, &i.right);
+        }
+    }
+
+    #[test]
+    fn parse_operator_precedence() {
+        for (input, expected) in &[
+            ("a + b * 2 = c", "((a + (b * 2)) = c)"),
+            ("a + b + c", "((a + b) + c)"),
+            ("a * b / c", "((a * b) / c)"),
+            ("-a * b", "((-a) * b)"),
+            ("!-a", "(NOT (-a))"),
+            ("a + b - c", "((a + b) - c)"),
+            ("a < b = c > d", "((a < b) = (c > d))"),
+            ("a <= b != c >= d", "((a <= b) != (c >= d))"),
+            ("a = 1 AND b = 2 OR c = 3", "(((a = 1) AND (b = 2)) OR (c = 3))"),
+            ("a = 1 OR b = 2 AND c = 3", "((a = 1) OR ((b = 2) AND (c = 3)))"),
+            ("(a + b) * c", "((a + b) * c)"),
+            ("-(a + b)", "(-(a + b))"),
+            ("a * (b + c) / d", "((a * (b + c)) / d)"),
+            ("NOT a = b", "(NOT (a = b))"),
+            ("NOT a AND b", "((NOT a) AND b)"),
+            ("!a = b", "(NOT (a = b))"),
+            ("!a AND b", "((NOT a) AND b)"),
+            ("not -a < b or c", "((NOT ((-a) < b)) OR c)"),
+        ] {
+            assert_eq!(format!("{}", parse(input)), *expected);
+        }
+    }
+
+    #[test]
+    fn parse_bad_infix_expression() {
+        for (input, expected_error) in [
+            ("1 +", ParseError::ExpectedExpression { found: TokenKind::Eof, span: at(3, 3) }),
+            ("(1 + 2", ParseError::UnexpectedToken { expected: vec![TokenKind::RParen], found: TokenKind::Eof, span: at(6, 6) }),
+        ] {
+            assert_eq!(parse_errors(input)[0], expected_error);
+        }
     }
 }